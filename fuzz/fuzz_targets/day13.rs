@@ -0,0 +1,24 @@
+#![no_main]
+
+use aoc::days::day13::{self, Term};
+use libfuzzer_sys::fuzz_target;
+
+// Any input is parsed or rejected without panicking, and whatever parses prints back the same.
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(pairs) = day13::parse(text) {
+        for (a, b) in pairs {
+            assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            assert_eq!(a == b, a.cmp(&b).is_eq());
+        }
+    }
+
+    for line in text.lines() {
+        if let Ok(term) = line.parse::<Term>() {
+            assert_eq!(Ok(&term), format!("{term:?}").parse::<Term>().as_ref());
+        }
+    }
+});
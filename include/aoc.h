@@ -0,0 +1,43 @@
+/* Generated from src/ffi.rs by `aoc header`, do not edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum aoc_status {
+    /* The answer was written to `out`. */
+    AOC_OK = 0,
+    /* There is no solution for the day. */
+    AOC_UNKNOWN_DAY = 1,
+    /* The part is not 1 or 2. */
+    AOC_UNKNOWN_PART = 2,
+    /* The input is malformed, or not UTF-8; `out` says where. */
+    AOC_PARSE_ERROR = 3,
+    /* The answer and its NUL do not fit in `out`. */
+    AOC_BUFFER_TOO_SMALL = 4,
+    /* A buffer is null but its size is not 0. */
+    AOC_INVALID_ARGUMENT = 5,
+    /* The solver panicked; `out` has the message. */
+    AOC_PANIC = 6,
+} aoc_status;
+
+/*
+ * Solves `part` of `day` on the `input_len` bytes of UTF-8 at `input`, and writes the answer to
+ * `out` as a NUL-terminated string. For a parse error or a panic the message is written instead,
+ * cut short if it does not fit. Unless it is null, `out_len` is set to the length of the answer
+ * or message without its NUL, so a buffer that was too small can be replaced by one of
+ * `*out_len + 1` bytes. `input` and `out` may be null if their size is 0.
+ */
+aoc_status aoc_solve(uint32_t day, uint32_t part, const char *input, size_t input_len, char *out,
+                     size_t out_cap, size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
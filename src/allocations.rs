@@ -0,0 +1,128 @@
+//! A global allocator that counts what each thread allocates, so what a day allocates can be
+//! measured and checked. It is always installed in the library's tests, and in the binary with
+//! the `count-allocations` feature.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// The system allocator, counting as it goes.
+pub struct Counting;
+
+/// What was allocated while [`measure`] ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Allocations {
+    /// Allocations and reallocations.
+    pub count: u64,
+    pub bytes: u64,
+    /// The most bytes allocated and not yet freed at any one time.
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+/// Counts an allocation of `bytes` that changes the live bytes by `change`.
+fn record(bytes: usize, change: isize) {
+    // a thread being torn down has no counts left to update
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        if bytes > 0 {
+            c.allocations += 1;
+            c.bytes += bytes as u64;
+        }
+        c.live += change as i64;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record(0, -(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            record(new_size, new_size as isize - layout.size() as isize);
+        }
+        new
+    }
+}
+
+/// Runs `f`, returning what it allocated on this thread, or `None` if [`Counting`] is not the
+/// global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    let before = COUNTS.with(|counts| {
+        let mut c = counts.get();
+        c.peak = c.live;
+        counts.set(c);
+        c
+    });
+    let result = f();
+    let after = COUNTS.with(Cell::get);
+
+    let allocations = INSTALLED.load(Ordering::Relaxed).then(|| Allocations {
+        count: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    });
+    (result, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{measure, Allocations};
+
+    #[test]
+    fn test() {
+        let (_, nothing) = measure(|| black_box(1 + 1));
+        assert_eq!(Some(Allocations::default()), nothing);
+
+        let (v, allocations) = measure(|| {
+            drop(black_box(vec![0u8; 100]));
+            let mut v: Vec<u64> = Vec::with_capacity(4);
+            v.extend([1, 2, 3, 4, 5]);
+            v
+        });
+        let allocations = allocations.unwrap();
+        assert_eq!(5, v.len());
+        assert_eq!(3, allocations.count);
+        assert!(allocations.bytes >= 100 + 32 + 40);
+        // the 100 bytes were freed before the vector was allocated
+        assert_eq!(100, allocations.peak);
+    }
+}
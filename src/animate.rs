@@ -0,0 +1,173 @@
+//! Step-by-step simulations of the days that move things around, drawn as maps of characters so
+//! they can be watched when an answer looks wrong.
+
+use crate::{
+    days::{day10, day14, day5, day9},
+    point::Point,
+    ParseError,
+};
+
+/// The days that can be animated.
+pub const DAYS: &[u8] = &[5, 9, 10, 14];
+
+/// A puzzle being simulated one step at a time.
+pub trait Simulation {
+    /// Moves on by one step, or returns `false` if the simulation has already finished.
+    fn step(&mut self) -> bool;
+
+    /// What to draw at `pos`.
+    fn cell(&self, pos: Point) -> char;
+
+    /// Where things are happening, which the viewport keeps in sight.
+    fn focus(&self) -> Point;
+
+    /// A line about the current step, like how far through the moves it is.
+    fn status(&self) -> String;
+}
+
+/// The simulation of `part` of `day` on `input`, for a day in [`DAYS`].
+pub fn simulation(day: u8, input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let part2 = part == 2;
+    Ok(match day {
+        5 => Box::new(day5::Animation::new(&day5::parse(input)?, part2)),
+        9 => Box::new(day9::Animation::new(
+            &day9::parse(input)?,
+            if part2 { 10 } else { 2 },
+        )),
+        10 => Box::new(day10::Animation::new(&day10::parse(input)?)),
+        14 => Box::new(day14::Animation::new(&day14::parse(input)?, part2)),
+        _ => unreachable!("day {day} cannot be animated"),
+    })
+}
+
+/// The part of a simulation's map that is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    /// The top left corner.
+    pub origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    /// A viewport with `focus` in its middle.
+    pub fn centered(width: usize, height: usize, focus: Point) -> Self {
+        let half = Point::new(width as isize / 2, height as isize / 2);
+        Viewport {
+            origin: focus - half,
+            width,
+            height,
+        }
+    }
+
+    /// Scrolls as little as needed to keep `focus` a quarter of the viewport away from its edges.
+    pub fn follow(&mut self, focus: Point) {
+        fn follow_axis(origin: &mut isize, len: usize, focus: isize) {
+            let (len, margin) = (len as isize, len as isize / 4);
+            if focus < *origin + margin {
+                *origin = focus - margin;
+            } else if focus >= *origin + len - margin {
+                *origin = focus - len + margin + 1;
+            }
+        }
+        follow_axis(&mut self.origin.x, self.width, focus.x);
+        follow_axis(&mut self.origin.y, self.height, focus.y);
+    }
+
+    /// The visible part of the map, a line per row.
+    pub fn render(&self, simulation: &dyn Simulation) -> Vec<String> {
+        (0..self.height as isize)
+            .map(|y| {
+                (0..self.width as isize)
+                    .map(|x| simulation.cell(self.origin + Point::new(x, y)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simulation, Viewport};
+    use crate::{days::day10, point::Point};
+
+    #[test]
+    fn test() {
+        let mut view = Viewport::centered(8, 4, Point::new(10, 10));
+        assert_eq!(Point::new(6, 8), view.origin);
+        view.follow(Point::new(11, 9));
+        assert_eq!(Point::new(6, 8), view.origin);
+        view.follow(Point::new(12, 11));
+        assert_eq!(Point::new(7, 9), view.origin);
+        view.follow(Point::new(-3, 0));
+        assert_eq!(Point::new(-5, -1), view.origin);
+
+        // the sand of day 14 piles up as in the puzzle's picture
+        let mut sand = simulation(
+            14,
+            "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9",
+            1,
+        )
+        .unwrap();
+        while sand.step() {}
+        let view = Viewport {
+            origin: Point::new(494, 0),
+            width: 10,
+            height: 10,
+        };
+        let picture = "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.";
+        assert_eq!(picture, view.render(&*sand).join("\n"));
+        assert_eq!(
+            "24 units of sand at rest, the next falls forever",
+            sand.status()
+        );
+
+        let mut rope = simulation(9, "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2", 1).unwrap();
+        while rope.step() {}
+        assert_eq!(
+            "step 24 of 24, the tail visited 13 positions",
+            rope.status()
+        );
+
+        let mut crane = simulation(
+            5,
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n",
+            2,
+        )
+        .unwrap();
+        assert!(crane.step());
+        assert!(!crane.step());
+        let view = Viewport {
+            origin: Point::new(0, -3),
+            width: 11,
+            height: 4,
+        };
+        let picture = "[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
+        assert_eq!(picture, view.render(&*crane).join("\n"));
+
+        // the screen drawn cycle by cycle is part 2's answer
+        let input = day10::generate(&mut crate::generate::Rng::new(10), 200);
+        let mut crt = simulation(10, &input, 2).unwrap();
+        while crt.step() {}
+        let view = Viewport {
+            origin: Point::ORIGIN,
+            width: 40,
+            height: 6,
+        };
+        let screen = day10::part2(&day10::parse(&input).unwrap());
+        assert_eq!(
+            screen.trim_start_matches('\n'),
+            view.render(&*crt).join("\n")
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+/// Known answers, read from a subset of TOML:
+///
+/// ```toml
+/// [day5.committed]
+/// part1 = "LBLVVTVLP"
+///
+/// [day9.my-input]
+/// part1 = 13
+/// ```
+///
+/// Each section names a day and an input (see [`Source::name`](crate::input::Source::name)). Answers are numbers or strings,
+/// multi-line answers use `"""` strings.
+pub struct Answers(HashMap<(u8, String, u8), String>);
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        let mut section = None;
+        let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        while let Some((n, line)) = lines.next() {
+            let err = |msg: &str| format!("line {n}: {msg}");
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = Some(parse_header(header).map_err(|msg| err(&msg))?);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| err("expected `key = value`"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(err(&format!("expected `part1` or `part2`, found `{key}`"))),
+            };
+            let (day, input): &(u8, String) = section
+                .as_ref()
+                .ok_or_else(|| err("answer outside of a `[day<n>.<input>]` section"))?;
+
+            let value = value.trim();
+            let answer = if let Some(first) = value.strip_prefix(r#"""""#) {
+                let mut string = first.to_owned();
+                while !string.contains(r#"""""#) {
+                    let (_, line) = lines.next().ok_or_else(|| err("unterminated string"))?;
+                    string.push('\n');
+                    string.push_str(line);
+                }
+                let (string, rest) = string.split_once(r#"""""#).unwrap();
+                if !rest.trim().is_empty() {
+                    return Err(err("expected end of line after string"));
+                }
+                // A newline right after the opening quotes is not part of the string.
+                unescape(string.strip_prefix('\n').unwrap_or(string)).map_err(|msg| err(&msg))?
+            } else if let Some(quoted) = value.strip_prefix('"') {
+                let string = quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| err("unterminated string"))?;
+                unescape(string).map_err(|msg| err(&msg))?
+            } else if value.parse::<i64>().is_ok() {
+                value.to_owned()
+            } else {
+                return Err(err(&format!(
+                    "expected a number or a string, found `{value}`"
+                )));
+            };
+
+            if answers
+                .insert((*day, input.clone(), part), answer)
+                .is_some()
+            {
+                return Err(err(&format!(
+                    "day {day} part {part} of `{input}` is already answered"
+                )));
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&(day, input.to_owned(), part))
+            .map(|answer| answer.trim_matches('\n'))
+    }
+}
+
+/// Reads `day<n>.<input>`, where the input name may be quoted.
+fn parse_header(header: &str) -> Result<(u8, String), String> {
+    let malformed = || format!("expected `[day<n>.<input>]`, found `[{header}]`");
+    let (day, input) = header.trim().split_once('.').ok_or_else(malformed)?;
+    let day = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(malformed)?;
+
+    let input = match input.strip_prefix('"').and_then(|i| i.strip_suffix('"')) {
+        Some(quoted) => unescape(quoted)?,
+        None if !input.is_empty()
+            && input
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+        {
+            input.to_owned()
+        }
+        None => return Err(malformed()),
+    };
+    Ok((day, input))
+}
+
+fn unescape(string: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            Some(c) => return Err(format!("unknown escape `\\{c}`")),
+            None => return Err("unfinished escape".to_owned()),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn test() {
+        let answers = Answers::parse(
+            r#"
+# Committed inputs
+[day5.committed]
+part1 = "LBLVVTVLP"
+part2 = 42
+
+[day10."my input"]
+part2 = """
+#..#
+.##.
+"""
+"#,
+        )
+        .unwrap();
+        assert_eq!(Some("LBLVVTVLP"), answers.get(5, "committed", 1));
+        assert_eq!(Some("42"), answers.get(5, "committed", 2));
+        assert_eq!(Some("#..#\n.##."), answers.get(10, "my input", 2));
+        assert_eq!(None, answers.get(10, "my input", 1));
+
+        assert_eq!(
+            Err("line 1: answer outside of a `[day<n>.<input>]` section".to_owned()),
+            Answers::parse("part1 = 1").map(|_| ())
+        );
+        assert_eq!(
+            Err("line 3: day 1 part 1 of `committed` is already answered".to_owned()),
+            Answers::parse("[day1.committed]\npart1 = 1\npart1 = 2").map(|_| ())
+        );
+        assert!(Answers::parse("[day1]").is_err());
+        assert!(Answers::parse("[day1.x]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day1.x]\npart1 = abc").is_err());
+        assert!(Answers::parse("[day1.x]\npart1 = \"\"\"\nabc").is_err());
+    }
+}
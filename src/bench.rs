@@ -0,0 +1,196 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    allocations::{self, Allocations},
+    days::Day,
+    ParseError,
+};
+
+/// The spread of one step's timings over all runs.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Report {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    /// What parse and each part allocate, if allocations are counted.
+    pub allocations: Option<[Allocations; 3]>,
+}
+
+/// Times parsing `input` and solving both parts from it, `runs` times over.
+///
+/// Every run parses afresh, so the parts never see an input another run already solved. The
+/// allocations are counted on one more run, so counting them does not slow down the timed ones.
+pub fn run(day: &Day, input: &str, runs: usize) -> Result<Report, ParseError> {
+    let mut samples = [(); 3].map(|_| Vec::with_capacity(runs));
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = day.parse(black_box(input))?;
+        samples[0].push(start.elapsed());
+
+        for part in 1..=2 {
+            let start = Instant::now();
+            black_box(day.solve(&parsed, part));
+            samples[part as usize].push(start.elapsed());
+        }
+    }
+
+    let (parsed, parse_allocations) = allocations::measure(|| day.parse(input));
+    let parsed = parsed?;
+    let [part1_allocations, part2_allocations] =
+        [1, 2].map(|part| allocations::measure(|| day.solve(&parsed, part)).1);
+
+    let [parse, part1, part2] = samples.map(Stats::new);
+    Ok(Report {
+        day: day.day,
+        parse,
+        part1,
+        part2,
+        allocations: parse_allocations
+            .zip(part1_allocations)
+            .zip(part2_allocations)
+            .map(|((parse, part1), part2)| [parse, part1, part2]),
+    })
+}
+
+impl Report {
+    fn steps(&self) -> [(&'static str, Stats, Option<Allocations>); 3] {
+        let allocations = |i: usize| self.allocations.map(|a| a[i]);
+        [
+            ("parse", self.parse, allocations(0)),
+            ("part1", self.part1, allocations(1)),
+            ("part2", self.part2, allocations(2)),
+        ]
+    }
+}
+
+/// `bytes` in the largest binary unit that keeps it above 1.
+fn bytes(bytes: u64) -> String {
+    let mut size = bytes as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if size < 1024.0 {
+            return match unit {
+                "B" => format!("{bytes} B"),
+                _ => format!("{size:.1} {unit}"),
+            };
+        }
+        size /= 1024.0;
+    }
+    format!("{size:.1} GiB")
+}
+
+/// One row per day and step, with durations and sizes scaled to a readable unit. The
+/// allocations are only shown when they were counted.
+pub fn table(reports: &[Report]) -> String {
+    let counted = reports.iter().all(|report| report.allocations.is_some());
+    let mut table = format!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "day", "step", "min", "median", "max"
+    );
+    if counted {
+        table += &format!("  {:>10}  {:>10}  {:>10}", "allocs", "bytes", "peak");
+    }
+    table += "\n";
+    for report in reports {
+        for (step, stats, allocations) in report.steps() {
+            table += &format!(
+                "{:>3}  {step:<5}  {:>10.1?}  {:>10.1?}  {:>10.1?}",
+                report.day, stats.min, stats.median, stats.max
+            );
+            if let Some(a) = allocations.filter(|_| counted) {
+                table += &format!(
+                    "  {:>10}  {:>10}  {:>10}",
+                    a.count,
+                    bytes(a.bytes),
+                    bytes(a.peak)
+                );
+            }
+            table += "\n";
+        }
+    }
+    table
+}
+
+/// The reports as a JSON document, durations in nanoseconds, with the allocations of every step
+/// when they were counted.
+pub fn json(runs: usize, reports: &[Report]) -> String {
+    let days: Vec<String> = reports
+        .iter()
+        .map(|report| {
+            let steps: Vec<String> = report
+                .steps()
+                .iter()
+                .map(|(step, stats, allocations)| {
+                    let allocations = allocations.map_or(String::new(), |a| {
+                        format!(
+                            r#","allocations":{},"bytes":{},"peak_bytes":{}"#,
+                            a.count, a.bytes, a.peak
+                        )
+                    });
+                    format!(
+                        r#""{step}":{{"min_ns":{},"median_ns":{},"max_ns":{}{allocations}}}"#,
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.max.as_nanos()
+                    )
+                })
+                .collect();
+            format!(r#"{{"day":{},{}}}"#, report.day, steps.join(","))
+        })
+        .collect();
+    format!(r#"{{"runs":{runs},"days":[{}]}}"#, days.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bytes, json, run, Stats};
+
+    #[test]
+    fn test() {
+        let stats = Stats::new([5, 1, 3].map(Duration::from_nanos).to_vec());
+        assert_eq!(
+            [1, 3, 5].map(Duration::from_nanos),
+            [stats.min, stats.median, stats.max]
+        );
+
+        let day6 = aoc::days::get(6).unwrap();
+        let report = run(day6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 3).unwrap();
+        assert_eq!(6, report.day);
+        assert_eq!(
+            cfg!(feature = "count-allocations"),
+            report.allocations.is_some()
+        );
+        assert!(report.part1.min <= report.part1.median && report.part1.median <= report.part1.max);
+        assert!(json(3, &[report]).starts_with(r#"{"runs":3,"days":[{"day":6,"parse":{"min_ns":"#));
+
+        assert!(run(day6, "MJQ", 3).is_err());
+
+        assert_eq!(
+            ["12 B", "1.5 KiB", "3.0 MiB"],
+            [12, 1536, 3 << 20].map(bytes)
+        );
+    }
+}
@@ -0,0 +1,851 @@
+use std::time::Duration;
+
+use aoc::point::Point;
+
+pub const USAGE: &str = "\
+usage: aoc run <day> [--part <1|2>] [--input <path|->] [--animate [--fps <n>] [--steps <n>]]
+               [--stream] [--trace | --trace-file <path>]
+       aoc run --all [--part <1|2>] [--jobs <n>] [--json] [--trace | --trace-file <path>]
+       aoc bench <day> [--runs <n>] [--input <path|->] [--json]
+       aoc bench --all [--runs <n>] [--json]
+       aoc verify [<day>|--all] [--input <path|->] [--answers <path>]
+       aoc fetch <day> [--force] [--base-url <url>]
+       aoc generate <day> [--seed <n>] [--size <n>]
+       aoc diff <day>|--all [--seeds <n>] [--size <n>]
+       aoc image <day> [--part <1|2>] [--input <path|->] [--output <path|->] [--scale <n>]
+                 [--view <x0>,<y0>,<x1>,<y1>]
+       aoc repl <day> [--input <path>]
+       aoc new <day> [--root <path>]
+       aoc header
+       aoc serve [--port <n>] [--jobs <n>] [--max-body <bytes>] [--timeout <seconds>]";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        days: Vec<u8>,
+        parts: Vec<u8>,
+        /// Where to read the input from, `-` for stdin; the committed input otherwise.
+        input: Option<String>,
+        /// Watch the simulation in the terminal before the answer is printed.
+        animate: Option<Animate>,
+        /// Solve the input as it is read rather than loading it first.
+        stream: bool,
+        trace: Option<Trace>,
+    },
+    /// Solve every day at once and summarise how each went.
+    RunAll {
+        days: Vec<u8>,
+        parts: Vec<u8>,
+        /// How many days are solved at the same time; as many as there are CPUs by default.
+        jobs: Option<usize>,
+        json: bool,
+        trace: Option<Trace>,
+    },
+    Bench {
+        days: Vec<u8>,
+        input: Option<String>,
+        /// How many times parse and both parts are timed.
+        runs: usize,
+        json: bool,
+    },
+    Verify {
+        days: Vec<u8>,
+        input: Option<String>,
+        /// The answers file to check against.
+        answers: String,
+    },
+    Fetch {
+        day: u8,
+        /// Download again even if the input was already fetched.
+        force: bool,
+        base_url: Option<String>,
+    },
+    Generate {
+        day: u8,
+        seed: u64,
+        size: usize,
+    },
+    /// Compare days with their brute-force oracles on generated inputs.
+    Diff {
+        days: Vec<u8>,
+        /// How many seeds, counting from 0, to generate inputs from.
+        seeds: u64,
+        size: usize,
+    },
+    /// Draw a day's map to a PPM or SVG file.
+    Image {
+        day: u8,
+        part: u8,
+        input: Option<String>,
+        /// Where to write the image, `-` for stdout; `day<n>.<ppm|svg>` otherwise.
+        output: Option<String>,
+        scale: usize,
+        /// The corners of the area drawn, for the days drawn as SVG.
+        view: Option<(Point, Point)>,
+    },
+    /// Answer commands about a day's parsed input, read from stdin.
+    Repl {
+        day: u8,
+        /// Where to read the input from; the committed input otherwise.
+        input: Option<String>,
+    },
+    /// Add the files of a day that has no solution yet.
+    New {
+        day: u8,
+        /// The crate to add it to; the one `aoc` was built from otherwise.
+        root: Option<String>,
+    },
+    /// Print the C header of the shared library.
+    Header,
+    /// Solve puzzles sent over HTTP.
+    Serve {
+        port: u16,
+        /// How many puzzles are solved at the same time; as many as there are CPUs by default.
+        jobs: Option<usize>,
+        /// The biggest input accepted, in bytes.
+        max_body: usize,
+        /// How long a request waits for its answer.
+        timeout: Duration,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Animate {
+    /// Frames drawn per second.
+    pub fps: u32,
+    /// Simulation steps between two frames.
+    pub steps: usize,
+}
+
+/// Where the days' intermediate data goes.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Trace {
+    Stderr,
+    File(String),
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+
+    let command = args.next().ok_or("missing command")?;
+    match command {
+        "fetch" => return parse_fetch(args),
+        "generate" => return parse_generate(args),
+        "diff" => return parse_diff(args),
+        "image" => return parse_image(args),
+        "serve" => return parse_serve(args),
+        "repl" => return parse_repl(args),
+        "new" => return parse_new(args),
+        "header" => {
+            return match args.next() {
+                Some(arg) => Err(format!("unexpected argument `{arg}`")),
+                None => Ok(Command::Header),
+            }
+        }
+        _ => {}
+    }
+    if !matches!(command, "run" | "bench" | "verify") {
+        return Err(format!("unknown command `{command}`"));
+    }
+
+    let mut days = None;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut runs = 10;
+    let mut json = false;
+    let mut answers = "answers.toml".to_owned();
+    let mut animate = false;
+    let mut fps = None;
+    let mut steps = None;
+    let mut jobs = None;
+    let mut trace = None;
+    let mut stream = false;
+
+    while let Some(arg) = args.next() {
+        match (command, arg) {
+            (_, "--all") => days = Some(aoc::days::DAYS.iter().map(|d| d.day).collect()),
+            ("run", "--part") => {
+                let part = args.next().ok_or("--part needs a value")?;
+                parts = vec![parse_part(part)?];
+            }
+            (_, "--input") => {
+                let path = args.next().ok_or("--input needs a path")?;
+                input = Some(path.to_owned());
+            }
+            ("bench", "--runs") => {
+                let n = args.next().ok_or("--runs needs a value")?;
+                runs = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("--runs must be a positive number, got `{n}`"))?;
+            }
+            ("bench" | "run", "--json") => json = true,
+            ("run", "--jobs") => {
+                let n = args.next().ok_or("--jobs needs a value")?;
+                jobs = Some(
+                    n.parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| format!("--jobs must be a positive number, got `{n}`"))?,
+                );
+            }
+            ("run", "--animate") => animate = true,
+            ("run", "--fps") => {
+                let n = args.next().ok_or("--fps needs a value")?;
+                fps = Some(
+                    n.parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| format!("--fps must be a positive number, got `{n}`"))?,
+                );
+            }
+            ("run", "--steps") => {
+                let n = args.next().ok_or("--steps needs a value")?;
+                steps = Some(
+                    n.parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| format!("--steps must be a positive number, got `{n}`"))?,
+                );
+            }
+            ("run", "--stream") => stream = true,
+            ("run", "--trace") => trace = Some(Trace::Stderr),
+            ("run", "--trace-file") => {
+                let path = args.next().ok_or("--trace-file needs a path")?;
+                trace = Some(Trace::File(path.to_owned()));
+            }
+            ("verify", "--answers") => {
+                let path = args.next().ok_or("--answers needs a path")?;
+                answers = path.to_owned();
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => days = Some(vec![parse_day(arg)?]),
+        }
+    }
+
+    let days: Vec<u8> = match (command, days) {
+        (_, Some(days)) => days,
+        ("verify", None) => aoc::days::DAYS.iter().map(|d| d.day).collect(),
+        _ => return Err("expected a day or --all".to_owned()),
+    };
+    if input.is_some() && days.len() != 1 {
+        return Err("--input needs a single day".to_owned());
+    }
+    let animate = match (animate, fps, steps) {
+        (false, None, None) => None,
+        (false, _, _) => return Err("--fps and --steps need --animate".to_owned()),
+        (true, fps, steps) => match days[..] {
+            [day] if aoc::animate::DAYS.contains(&day) => Some(Animate {
+                fps: fps.unwrap_or(20),
+                steps: steps.unwrap_or(1),
+            }),
+            [day] => return Err(format!("no animation for day {day}")),
+            _ => return Err("--animate needs a single day".to_owned()),
+        },
+    };
+    if stream {
+        match days[..] {
+            _ if animate.is_some() => {
+                return Err("--stream cannot be used with --animate".to_owned())
+            }
+            [day] if !aoc::stream::DAYS.contains(&day) => {
+                return Err(format!("no streaming for day {day}"))
+            }
+            [_] => {}
+            _ => return Err("--stream needs a single day".to_owned()),
+        }
+    }
+    if command == "run" && days.len() > 1 {
+        return Ok(Command::RunAll {
+            days,
+            parts,
+            jobs,
+            json,
+            trace,
+        });
+    }
+    if command == "run" && (jobs.is_some() || json) {
+        return Err("--jobs and --json need --all".to_owned());
+    }
+    Ok(match command {
+        "run" => Command::Run {
+            days,
+            parts,
+            input,
+            animate,
+            stream,
+            trace,
+        },
+        "verify" => Command::Verify {
+            days,
+            input,
+            answers,
+        },
+        _ => Command::Bench {
+            days,
+            input,
+            runs,
+            json,
+        },
+    })
+}
+
+fn parse_fetch<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut day = None;
+    let mut force = false;
+    let mut base_url = None;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--force" => force = true,
+            "--base-url" => {
+                let url = args.next().ok_or("--base-url needs a URL")?;
+                base_url = Some(url.to_owned());
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            // Inputs exist for every day of the event, solved or not.
+            _ => {
+                day = Some(
+                    arg.parse()
+                        .ok()
+                        .filter(|day| (1..=25).contains(day))
+                        .ok_or_else(|| format!("no puzzle for day `{arg}`"))?,
+                )
+            }
+        }
+    }
+
+    Ok(Command::Fetch {
+        day: day.ok_or("expected a day")?,
+        force,
+        base_url,
+    })
+}
+
+fn parse_generate<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = 100;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--seed" => {
+                let n = args.next().ok_or("--seed needs a value")?;
+                seed = n
+                    .parse()
+                    .map_err(|_| format!("--seed must be a number, got `{n}`"))?;
+            }
+            "--size" => {
+                let n = args.next().ok_or("--size needs a value")?;
+                size = n
+                    .parse()
+                    .map_err(|_| format!("--size must be a number, got `{n}`"))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => day = Some(parse_day(arg)?),
+        }
+    }
+
+    Ok(Command::Generate {
+        day: day.ok_or("expected a day")?,
+        seed,
+        size,
+    })
+}
+
+fn parse_diff<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut days = None;
+    let mut seeds = 100;
+    let mut size = 10;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => days = Some(aoc::oracle::DAYS.to_vec()),
+            "--seeds" => {
+                let n = args.next().ok_or("--seeds needs a value")?;
+                seeds = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("--seeds must be a positive number, got `{n}`"))?;
+            }
+            "--size" => {
+                let n = args.next().ok_or("--size needs a value")?;
+                size = n
+                    .parse()
+                    .map_err(|_| format!("--size must be a number, got `{n}`"))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => {
+                let day = parse_day(arg)?;
+                if !aoc::oracle::DAYS.contains(&day) {
+                    return Err(format!("no oracle for day {day}"));
+                }
+                days = Some(vec![day]);
+            }
+        }
+    }
+
+    Ok(Command::Diff {
+        days: days.ok_or("expected a day or --all")?,
+        seeds,
+        size,
+    })
+}
+
+fn parse_image<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut day = None;
+    let mut part = 1;
+    let mut input = None;
+    let mut output = None;
+    let mut scale = 1;
+    let mut view = None;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" => part = parse_part(args.next().ok_or("--part needs a value")?)?,
+            "--input" => {
+                let path = args.next().ok_or("--input needs a path")?;
+                input = Some(path.to_owned());
+            }
+            "--output" => {
+                let path = args.next().ok_or("--output needs a path")?;
+                output = Some(path.to_owned());
+            }
+            "--scale" => {
+                let n = args.next().ok_or("--scale needs a value")?;
+                scale = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("--scale must be a positive number, got `{n}`"))?;
+            }
+            "--view" => {
+                let corners = args.next().ok_or("--view needs corners")?;
+                view = Some(parse_view(corners)?);
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => {
+                let n = parse_day(arg)?;
+                if !aoc::image::DAYS.contains(&n) {
+                    return Err(format!("no image for day {n}"));
+                }
+                day = Some(n);
+            }
+        }
+    }
+
+    Ok(Command::Image {
+        day: day.ok_or("expected a day")?,
+        part,
+        input,
+        output,
+        scale,
+        view,
+    })
+}
+
+fn parse_repl<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut day = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--input" => {
+                let path = args.next().ok_or("--input needs a path")?;
+                // stdin is where the commands come from
+                if path == "-" {
+                    return Err("--input must be a file for repl".to_owned());
+                }
+                input = Some(path.to_owned());
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => {
+                let n = parse_day(arg)?;
+                if !aoc::repl::DAYS.contains(&n) {
+                    return Err(format!("no repl for day {n}"));
+                }
+                day = Some(n);
+            }
+        }
+    }
+
+    Ok(Command::Repl {
+        day: day.ok_or("expected a day")?,
+        input,
+    })
+}
+
+fn parse_new<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut day = None;
+    let mut root = None;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--root" => {
+                let path = args.next().ok_or("--root needs a path")?;
+                root = Some(path.to_owned());
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => {
+                let n = arg
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("no puzzle for day `{arg}`"))?;
+                if aoc::days::get(n).is_some() {
+                    return Err(format!("day {n} already has a solution"));
+                }
+                day = Some(n);
+            }
+        }
+    }
+
+    Ok(Command::New {
+        day: day.ok_or("expected a day")?,
+        root,
+    })
+}
+
+fn parse_serve<'a>(args: impl Iterator<Item = &'a str>) -> Result<Command, String> {
+    let mut args = args;
+    let mut port = 2022;
+    let mut jobs = None;
+    let mut max_body = 16 << 20;
+    let mut timeout = 60;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--port" => {
+                let n = args.next().ok_or("--port needs a value")?;
+                port = n
+                    .parse()
+                    .map_err(|_| format!("--port must be a port number, got `{n}`"))?;
+            }
+            "--jobs" => {
+                let n = args.next().ok_or("--jobs needs a value")?;
+                jobs = Some(
+                    n.parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| format!("--jobs must be a positive number, got `{n}`"))?,
+                );
+            }
+            "--max-body" => {
+                let n = args.next().ok_or("--max-body needs a value")?;
+                max_body = n
+                    .parse()
+                    .map_err(|_| format!("--max-body must be a number, got `{n}`"))?;
+            }
+            "--timeout" => {
+                let n = args.next().ok_or("--timeout needs a value")?;
+                timeout = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("--timeout must be a positive number, got `{n}`"))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`")),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(Command::Serve {
+        port,
+        jobs,
+        max_body,
+        timeout: Duration::from_secs(timeout),
+    })
+}
+
+/// Reads `x0,y0,x1,y1`, the top left and bottom right corners of an area.
+fn parse_view(arg: &str) -> Result<(Point, Point), String> {
+    let invalid =
+        || format!("--view must be `<x0>,<y0>,<x1>,<y1>` with x0 <= x1 and y0 <= y1, got `{arg}`");
+    let n: Vec<isize> = arg
+        .split(',')
+        .map(|n| n.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<_, _>>()?;
+    match n[..] {
+        [x0, y0, x1, y1] if x0 <= x1 && y0 <= y1 => Ok((Point::new(x0, y0), Point::new(x1, y1))),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.parse()
+        .ok()
+        .filter(|&day| aoc::days::get(day).is_some())
+        .ok_or_else(|| format!("no solution for day `{arg}`"))
+}
+
+fn parse_part(arg: &str) -> Result<u8, String> {
+    match arg {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("part must be 1 or 2, got `{arg}`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc::point::Point;
+
+    use super::{parse, Animate, Command, Trace};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test() {
+        let all: Vec<u8> = aoc::days::DAYS.iter().map(|d| d.day).collect();
+        assert_eq!(
+            Ok(Command::Run {
+                days: vec![12],
+                parts: vec![2],
+                input: None,
+                animate: None,
+                stream: false,
+                trace: None
+            }),
+            parse(&args("run 12 --part 2"))
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                days: vec![5],
+                parts: vec![1, 2],
+                input: Some("-".to_owned()),
+                animate: None,
+                stream: false,
+                trace: None
+            }),
+            parse(&args("run 5 --input -"))
+        );
+        assert_eq!(
+            Ok(Command::RunAll {
+                days: all.clone(),
+                parts: vec![1, 2],
+                jobs: None,
+                json: false,
+                trace: None
+            }),
+            parse(&args("run --all"))
+        );
+        assert_eq!(
+            Ok(Command::RunAll {
+                days: all.clone(),
+                parts: vec![2],
+                jobs: Some(4),
+                json: true,
+                trace: Some(Trace::File("trace.txt".to_owned()))
+            }),
+            parse(&args(
+                "run --all --jobs 4 --json --part 2 --trace-file trace.txt"
+            ))
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                days: vec![7],
+                parts: vec![1, 2],
+                input: None,
+                animate: None,
+                stream: false,
+                trace: Some(Trace::Stderr)
+            }),
+            parse(&args("run 7 --trace"))
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                days: vec![6],
+                parts: vec![1, 2],
+                input: Some("-".to_owned()),
+                animate: None,
+                stream: true,
+                trace: None
+            }),
+            parse(&args("run 6 --stream --input -"))
+        );
+        assert!(parse(&args("run 7 --stream")).is_err());
+        assert!(parse(&args("run --all --stream")).is_err());
+        assert!(parse(&args("run 9 --stream --animate")).is_err());
+        assert!(parse(&args("run 7 --trace-file")).is_err());
+        assert!(parse(&args("bench 7 --trace")).is_err());
+        assert!(parse(&args("run 3 --jobs 4")).is_err());
+        assert!(parse(&args("run --all --jobs 0")).is_err());
+        assert_eq!(
+            Ok(Command::Run {
+                days: vec![14],
+                parts: vec![1],
+                input: None,
+                animate: Some(Animate { fps: 60, steps: 1 }),
+                stream: false,
+                trace: None
+            }),
+            parse(&args("run 14 --fps 60 --animate --part 1"))
+        );
+        assert!(parse(&args("run 12 --animate")).is_err());
+        assert!(parse(&args("run --all --animate")).is_err());
+        assert!(parse(&args("run 9 --steps 10")).is_err());
+        assert!(parse(&args("run 9 --animate --fps 0")).is_err());
+        assert!(parse(&args("run --all --input day1.txt")).is_err());
+        assert!(parse(&args("run 42")).is_err());
+        assert!(parse(&args("run 1 --part 3")).is_err());
+        assert!(parse(&args("run")).is_err());
+
+        assert_eq!(
+            Ok(Command::Bench {
+                days: vec![16],
+                input: None,
+                runs: 50,
+                json: true
+            }),
+            parse(&args("bench 16 --runs 50 --json"))
+        );
+        assert_eq!(
+            Ok(Command::Bench {
+                days: all.clone(),
+                input: None,
+                runs: 10,
+                json: false
+            }),
+            parse(&args("bench --all"))
+        );
+        assert!(parse(&args("bench 1 --runs 0")).is_err());
+        assert!(parse(&args("bench 1 --part 1")).is_err());
+        assert!(parse(&args("run 1 --json")).is_err());
+        assert!(parse(&args("verify --json")).is_err());
+
+        assert_eq!(
+            Ok(Command::Verify {
+                days: all.clone(),
+                input: None,
+                answers: "answers.toml".to_owned()
+            }),
+            parse(&args("verify"))
+        );
+        assert_eq!(
+            Ok(Command::Verify {
+                days: vec![7],
+                input: Some("day7.txt".to_owned()),
+                answers: "mine.toml".to_owned()
+            }),
+            parse(&args("verify 7 --input day7.txt --answers mine.toml"))
+        );
+
+        assert_eq!(
+            Ok(Command::Fetch {
+                day: 20,
+                force: true,
+                base_url: Some("http://localhost:8080".to_owned())
+            }),
+            parse(&args("fetch 20 --force --base-url http://localhost:8080"))
+        );
+        assert!(parse(&args("fetch 26")).is_err());
+        assert!(parse(&args("fetch")).is_err());
+
+        assert_eq!(
+            Ok(Command::Generate {
+                day: 15,
+                seed: 3,
+                size: 100
+            }),
+            parse(&args("generate 15 --seed 3"))
+        );
+        assert!(parse(&args("generate 15 --size x")).is_err());
+
+        assert_eq!(
+            Ok(Command::Diff {
+                days: vec![11, 15, 16],
+                seeds: 20,
+                size: 10
+            }),
+            parse(&args("diff --all --seeds 20"))
+        );
+        assert!(parse(&args("diff 12")).is_err());
+
+        assert_eq!(
+            Ok(Command::Image {
+                day: 15,
+                part: 1,
+                input: None,
+                output: Some("-".to_owned()),
+                scale: 1,
+                view: Some((Point::new(-5, 0), Point::new(20, 20)))
+            }),
+            parse(&args("image 15 --view -5,0,20,20 --output -"))
+        );
+        assert_eq!(
+            Ok(Command::Image {
+                day: 12,
+                part: 2,
+                input: Some("day12.txt".to_owned()),
+                output: None,
+                scale: 4,
+                view: None
+            }),
+            parse(&args("image 12 --part 2 --scale 4 --input day12.txt"))
+        );
+        assert!(parse(&args("image 9")).is_err());
+        assert!(parse(&args("image 15 --view 3,0,1,2")).is_err());
+        assert!(parse(&args("image 15 --view 1,2,3")).is_err());
+        assert!(parse(&args("diff 16 --seeds 0")).is_err());
+
+        assert_eq!(
+            Ok(Command::Repl {
+                day: 16,
+                input: Some("day16.txt".to_owned())
+            }),
+            parse(&args("repl 16 --input day16.txt"))
+        );
+        assert!(parse(&args("repl 1")).is_err());
+        assert!(parse(&args("repl 7 --input -")).is_err());
+
+        let last = *all.last().unwrap();
+        assert_eq!(
+            Ok(Command::New {
+                day: last + 1,
+                root: None
+            }),
+            parse(&args(&format!("new {}", last + 1)))
+        );
+        assert!(parse(&args(&format!("new {last}"))).is_err());
+        assert!(parse(&args("new 26")).is_err());
+
+        assert_eq!(Ok(Command::Header), parse(&args("header")));
+        assert!(parse(&args("header 3")).is_err());
+
+        assert_eq!(
+            Ok(Command::Serve {
+                port: 2022,
+                jobs: None,
+                max_body: 16 << 20,
+                timeout: Duration::from_secs(60)
+            }),
+            parse(&args("serve"))
+        );
+        assert_eq!(
+            Ok(Command::Serve {
+                port: 8080,
+                jobs: Some(2),
+                max_body: 1000,
+                timeout: Duration::from_secs(5)
+            }),
+            parse(&args(
+                "serve --port 8080 --jobs 2 --max-body 1000 --timeout 5"
+            ))
+        );
+        assert!(parse(&args("serve --port 70000")).is_err());
+        assert!(parse(&args("serve --timeout 0")).is_err());
+        assert!(parse(&args("serve 3")).is_err());
+    }
+}
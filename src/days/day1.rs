@@ -0,0 +1,113 @@
+use std::io::BufRead;
+
+use crate::{
+    generate::Rng,
+    parse::{Lines, ReadError, ReadLines},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(elfs: &Self::Input) -> Answer {
+        part1(elfs).into()
+    }
+
+    fn part2(elfs: &Self::Input) -> Answer {
+        part2(elfs).into()
+    }
+}
+
+/// Calories carried by each elf, most first.
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut elfs = vec![0];
+    for mut line in Lines::new(Day1::DAY, input) {
+        if line.is_empty() {
+            elfs.push(0);
+        } else {
+            *elfs.last_mut().unwrap() += line.number::<usize>()?;
+            line.end()?;
+        }
+    }
+    elfs.sort_by(|a, b| b.cmp(a));
+    Ok(elfs)
+}
+
+/// Both parts, reading `input` as it goes and keeping only the three biggest totals.
+pub fn stream(input: impl BufRead) -> Result<(usize, usize), ReadError> {
+    let mut lines = ReadLines::new(Day1::DAY, input);
+    let mut top = [0; 3];
+    let mut elf = 0;
+    while let Some(mut line) = lines.next_line()? {
+        if line.is_empty() {
+            keep_top(&mut top, elf);
+            elf = 0;
+        } else {
+            elf += line.number::<usize>()?;
+            line.end()?;
+        }
+    }
+    keep_top(&mut top, elf);
+    Ok((top[0], top.iter().sum()))
+}
+
+/// Puts `elf` among the biggest totals, biggest first, if it is one of them.
+fn keep_top(top: &mut [usize; 3], elf: usize) {
+    if elf > top[2] {
+        top[2] = elf;
+        top.sort_by(|a, b| b.cmp(a));
+    }
+}
+
+pub fn part1(elfs: &[usize]) -> usize {
+    elfs[0]
+}
+
+pub fn part2(elfs: &[usize]) -> usize {
+    elfs.iter().take(3).sum()
+}
+
+/// Snacks for `size` elves, at least three.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut elfs = Vec::new();
+    for _ in 0..size.max(3) {
+        let snacks: Vec<String> = (0..rng.range(1..=8))
+            .map(|_| rng.range(1000..=60000).to_string())
+            .collect();
+        elfs.push(snacks.join("\n"));
+    }
+    elfs.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn test() {
+        let input = r"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        let elfs = parse(input).unwrap();
+        assert_eq!(24000, part1(&elfs));
+        assert_eq!(45000, part2(&elfs));
+    }
+}
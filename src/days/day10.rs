@@ -0,0 +1,385 @@
+use std::{cmp::Ordering, io::BufRead, iter};
+
+use crate::{
+    animate::Simulation,
+    generate::Rng,
+    parse::{Cursor, Lines, ReadError, ReadLines},
+    point::Point,
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        part1(program).into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        part2(program).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Lines::new(Day10::DAY, input)
+        .map(|mut line| parse_instruction(&mut line))
+        .collect()
+}
+
+fn parse_instruction(line: &mut Cursor) -> Result<Instruction, ParseError> {
+    let instruction = match line.one_of(&["addx ", "noop"])? {
+        "addx " => Instruction::Addx(line.number()?),
+        _ => Instruction::Noop,
+    };
+    line.end()?;
+    Ok(instruction)
+}
+
+/// The X register during every cycle of the program, and during the cycle after it finishes.
+pub fn registers(program: &[Instruction]) -> impl Iterator<Item = i64> + '_ {
+    let mut instructions = program.iter();
+    let mut x = 1;
+    // the value an `addx` adds at the end of its second cycle
+    let mut adding = None;
+    let mut finished = false;
+    iter::from_fn(move || {
+        let during = x;
+        if let Some(n) = adding.take() {
+            x += n;
+        } else {
+            match instructions.next() {
+                Some(&Instruction::Addx(n)) => adding = Some(n),
+                Some(Instruction::Noop) => {}
+                None if finished => return None,
+                None => finished = true,
+            }
+        }
+        Some(during)
+    })
+}
+
+pub fn part1(program: &[Instruction]) -> i64 {
+    registers(program)
+        .zip(1..)
+        .filter(|&(_, cycle)| (cycle - 20) % 40 == 0)
+        .map(|(x, cycle)| cycle * x)
+        .sum()
+}
+
+/// Whether the sprite centred on `x` covers the pixel the CRT draws during cycle `cycle`.
+fn lit(cycle: usize, x: i64) -> bool {
+    ((cycle % 40) as i64 - x).abs() < 2
+}
+
+/// The screen, a line of 40 pixels per row, each line starting with a newline.
+pub fn part2(program: &[Instruction]) -> String {
+    let mut screen = String::new();
+    for (cycle, x) in registers(program).take(240).enumerate() {
+        if cycle % 40 == 0 {
+            screen.push('\n');
+        }
+        screen.push(if lit(cycle, x) { '#' } else { '.' });
+    }
+    screen
+}
+
+/// Both parts, running each instruction as it is read from `input`.
+pub fn stream(input: impl BufRead) -> Result<(i64, String), ReadError> {
+    let mut lines = ReadLines::new(Day10::DAY, input);
+    let mut x = 1;
+    let mut cycle = 1;
+    let mut strength = 0;
+    let mut screen = String::new();
+    let mut tick = |x: i64| {
+        if (cycle - 20) % 40 == 0 {
+            strength += cycle * x;
+        }
+        let pixel = cycle as usize - 1;
+        if pixel < 240 {
+            if pixel.is_multiple_of(40) {
+                screen.push('\n');
+            }
+            screen.push(if lit(pixel, x) { '#' } else { '.' });
+        }
+        cycle += 1;
+    };
+    while let Some(mut line) = lines.next_line()? {
+        match parse_instruction(&mut line)? {
+            Instruction::Noop => tick(x),
+            Instruction::Addx(n) => {
+                tick(x);
+                tick(x);
+                x += n;
+            }
+        }
+    }
+    // like `registers`, the cycle after the program ends
+    tick(x);
+    Ok((strength, screen))
+}
+
+/// The CRT drawing a pixel per step. The sprite is shown under the screen as `=`, and the
+/// pixel being drawn as `@`.
+pub struct Animation {
+    registers: Vec<i64>,
+    cycle: usize,
+}
+
+impl Animation {
+    pub fn new(program: &[Instruction]) -> Self {
+        Animation {
+            registers: registers(program).take(240).collect(),
+            cycle: 0,
+        }
+    }
+
+    fn x(&self) -> i64 {
+        let last = self.registers.len().saturating_sub(1);
+        self.registers
+            .get(self.cycle.min(last))
+            .copied()
+            .unwrap_or(1)
+    }
+}
+
+impl Simulation for Animation {
+    fn step(&mut self) -> bool {
+        if self.cycle == self.registers.len() {
+            return false;
+        }
+        self.cycle += 1;
+        true
+    }
+
+    fn cell(&self, pos: Point) -> char {
+        if !(0..40).contains(&pos.x) {
+            return ' ';
+        }
+        if pos.y == 7 {
+            return if (pos.x as i64 - self.x()).abs() < 2 {
+                '='
+            } else {
+                ' '
+            };
+        }
+        if !(0..6).contains(&pos.y) {
+            return ' ';
+        }
+        let pixel = (pos.y * 40 + pos.x) as usize;
+        match pixel.cmp(&self.cycle) {
+            Ordering::Less if lit(pixel, self.registers[pixel]) => '#',
+            Ordering::Less => '.',
+            Ordering::Equal if pixel < self.registers.len() => '@',
+            _ => ' ',
+        }
+    }
+
+    /// The pixel being drawn.
+    fn focus(&self) -> Point {
+        let cycle = self.cycle.min(239) as isize;
+        Point::new(cycle % 40, cycle / 40)
+    }
+
+    fn status(&self) -> String {
+        format!("cycle {}: X = {}", self.cycle + 1, self.x())
+    }
+}
+
+/// A program of `size` instructions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            if rng.chance(1, 3) {
+                "noop\n".to_owned()
+            } else {
+                format!("addx {}\n", rng.range(-20..=20))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+    use crate::allocations::measure;
+
+    #[test]
+    fn test() {
+        let input = r"addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+        let program = parse(input).unwrap();
+        assert_eq!(13140, part1(&program));
+        // the register values are worked out as they are needed
+        let (_, allocations) = measure(|| part1(&program));
+        assert_eq!(0, allocations.unwrap().count);
+        assert_eq!(
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+                .trim_matches('\n')
+                .to_owned(),
+            part2(&program).trim_matches('\n')
+        );
+    }
+}
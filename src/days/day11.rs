@@ -0,0 +1,493 @@
+use std::{cmp::Reverse, collections::VecDeque};
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, Lines},
+    trace::{self, trace},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    test: u64,
+    operation: Operation,
+    if_true_monkey: usize,
+    if_false_monkey: usize,
+    items_inspected: u64,
+}
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Double,
+    Square,
+}
+
+impl Operation {
+    fn apply(self, old: u64) -> u64 {
+        match self {
+            Operation::Add(n) => old + n,
+            Operation::Mul(n) => old * n,
+            Operation::Double => old + old,
+            Operation::Square => old * old,
+        }
+    }
+}
+
+fn get_operation(line: &mut Cursor) -> Result<Operation, ParseError> {
+    line.literal("old ")?;
+    let op = line.one_of(&["+ ", "* "])?;
+    let rhs = if line.eat("old") {
+        None
+    } else {
+        Some(line.number::<u64>()?)
+    };
+    line.end()?;
+
+    Ok(match (op, rhs) {
+        ("+ ", None) => Operation::Double,
+        ("+ ", Some(rhs)) => Operation::Add(rhs),
+        (_, None) => Operation::Square,
+        (_, Some(rhs)) => Operation::Mul(rhs),
+    })
+}
+
+impl Monkey {
+    /// Parses the notes on monkey number `index`, along with where its two throw targets are written.
+    fn parse<'a>(
+        lines: &mut Lines<'a>,
+        index: usize,
+    ) -> Result<(Self, [(usize, Cursor<'a>); 2]), ParseError> {
+        let mut line = lines.expect_line("a monkey")?;
+        line.literal("Monkey ")?;
+        let number = line;
+        if line.number::<usize>()? != index {
+            return Err(number.error(format!("monkey number {index}")));
+        }
+        line.literal(":")?;
+        line.end()?;
+
+        let mut line = lines.expect_line("the starting items")?;
+        line.literal("  Starting items:")?;
+        let mut items = VecDeque::new();
+        while line.eat(" ") {
+            items.push_back(line.number()?);
+            if !line.eat(",") {
+                break;
+            }
+        }
+        line.end()?;
+
+        let mut line = lines.expect_line("the operation")?;
+        line.literal("  Operation: new = ")?;
+        let operation = get_operation(&mut line)?;
+
+        let mut line = lines.expect_line("the test")?;
+        line.literal("  Test: divisible by ")?;
+        let divisor = line;
+        let test: u64 = line.number()?;
+        if test == 0 {
+            return Err(divisor.error("a divisor above 0"));
+        }
+        line.end()?;
+
+        let mut throw = |outcome: &str| {
+            let mut line = lines.expect_line(&format!("where to throw on {outcome}"))?;
+            line.literal(&format!("    If {outcome}: throw to monkey "))?;
+            let target = line;
+            let monkey = line.number()?;
+            if monkey == index {
+                return Err(target.error(format!("a monkey other than {index}")));
+            }
+            line.end()?;
+            Ok((monkey, target))
+        };
+        let (if_true_monkey, true_target) = throw("true")?;
+        let (if_false_monkey, false_target) = throw("false")?;
+
+        let monkey = Monkey {
+            items,
+            test,
+            operation,
+            if_true_monkey,
+            if_false_monkey,
+            items_inspected: 0,
+        };
+        Ok((
+            monkey,
+            [
+                (if_true_monkey, true_target),
+                (if_false_monkey, false_target),
+            ],
+        ))
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        part1(monkeys).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        part2(monkeys).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut lines = Lines::new(Day11::DAY, input);
+    let mut monkeys = Vec::new();
+    let mut targets = Vec::new();
+    loop {
+        let (monkey, monkey_targets) = Monkey::parse(&mut lines, monkeys.len())?;
+        monkeys.push(monkey);
+        targets.extend(monkey_targets);
+
+        match lines.next() {
+            Some(line) => line.end()?,
+            None => break,
+        }
+    }
+    // the monkey business multiplies the two busiest monkeys
+    if monkeys.len() < 2 {
+        return Err(lines.end_of_input("a second monkey"));
+    }
+
+    for (target, at) in targets {
+        if target >= monkeys.len() {
+            return Err(at.error(format!("a monkey below {}", monkeys.len())));
+        }
+    }
+    Ok(monkeys)
+}
+
+pub fn part1(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let len = monkeys.len();
+
+    for round in 1..=20 {
+        for i in 0..len {
+            while let Some(item) = monkeys[i].items.pop_front() {
+                let item = monkeys[i].operation.apply(item);
+                let item = item / 3;
+
+                let idx = if item.is_multiple_of(monkeys[i].test) {
+                    monkeys[i].if_true_monkey
+                } else {
+                    monkeys[i].if_false_monkey
+                };
+
+                monkeys[idx].items.push_back(item);
+                monkeys[i].items_inspected += 1;
+            }
+        }
+        trace_round(1, round, &monkeys);
+    }
+
+    monkeys.sort_by_key(|m| Reverse(m.items_inspected));
+    monkeys[..2].iter().map(|m| m.items_inspected).product()
+}
+
+pub fn part2(monkeys: &[Monkey]) -> u64 {
+    part2_after(monkeys, 10_000)
+}
+
+/// The monkey business of part 2 after `rounds` rounds instead of 10000.
+pub fn part2_after(monkeys: &[Monkey], rounds: usize) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let len = monkeys.len();
+
+    // take the lcm of all the divisors
+    // I hate that aoc made me reverse engineer this
+    let absolute_limit: u64 = monkeys.iter().map(|m| m.test).product();
+
+    for round in 1..=rounds {
+        for i in 0..len {
+            while let Some(item) = monkeys[i].items.pop_front() {
+                let item = monkeys[i].operation.apply(item);
+                let item = item % absolute_limit;
+
+                let idx = if item.is_multiple_of(monkeys[i].test) {
+                    monkeys[i].if_true_monkey
+                } else {
+                    monkeys[i].if_false_monkey
+                };
+
+                monkeys[idx].items.push_back(item);
+                monkeys[i].items_inspected += 1;
+            }
+        }
+        trace_round(2, round, &monkeys);
+    }
+
+    monkeys.sort_by_key(|m| Reverse(m.items_inspected));
+    monkeys[..2].iter().map(|m| m.items_inspected).product()
+}
+
+/// How many items each monkey has inspected after `round` of `part`.
+fn trace_round(part: u8, round: usize, monkeys: &[Monkey]) {
+    if trace::enabled() {
+        for (monkey, m) in monkeys.iter().enumerate() {
+            trace!(
+                Day11::DAY,
+                "round",
+                part = part,
+                round = round,
+                monkey = monkey,
+                inspected = m.items_inspected,
+            );
+        }
+    }
+}
+
+/// An exact worry level, in base 2^32 digits with the lowest first.
+#[derive(Clone)]
+struct Worry(Vec<u32>);
+
+impl Worry {
+    fn new(n: u64) -> Self {
+        Worry(vec![n as u32, (n >> 32) as u32]).trimmed()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut sum = Vec::with_capacity(self.0.len().max(other.0.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.0.len().max(other.0.len()) {
+            let digit = carry
+                + u64::from(*self.0.get(i).unwrap_or(&0))
+                + u64::from(*other.0.get(i).unwrap_or(&0));
+            sum.push(digit as u32);
+            carry = digit >> 32;
+        }
+        sum.push(carry as u32);
+        Worry(sum).trimmed()
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut product = vec![0u32; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.0.iter().enumerate() {
+                let digit = u64::from(product[i + j]) + u64::from(a) * u64::from(b) + carry;
+                product[i + j] = digit as u32;
+                carry = digit >> 32;
+            }
+            product[i + other.0.len()] = carry as u32;
+        }
+        Worry(product).trimmed()
+    }
+
+    fn rem(&self, n: u64) -> u64 {
+        self.0.iter().rev().fold(0, |rem, &digit| {
+            ((u128::from(rem) << 32 | u128::from(digit)) % u128::from(n)) as u64
+        })
+    }
+
+    fn div(&self, n: u32) -> Self {
+        let mut rem = 0;
+        let mut quotient: Vec<u32> = self
+            .0
+            .iter()
+            .rev()
+            .map(|&digit| {
+                let value = rem << 32 | u64::from(digit);
+                rem = value % u64::from(n);
+                (value / u64::from(n)) as u32
+            })
+            .collect();
+        quotient.reverse();
+        Worry(quotient).trimmed()
+    }
+
+    fn trimmed(mut self) -> Self {
+        while self.0.len() > 1 && self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    fn apply(&self, operation: Operation) -> Self {
+        match operation {
+            Operation::Add(n) => self.add(&Worry::new(n)),
+            Operation::Mul(n) => self.mul(&Worry::new(n)),
+            Operation::Double => self.add(self),
+            Operation::Square => self.mul(self),
+        }
+    }
+}
+
+/// Worry levels the oracle gives up on, as multiplying them gets too slow.
+const ORACLE_DIGITS: usize = 128;
+
+/// Part 1 or, with `relief` off, part 2 after `rounds` rounds, keeping every worry level exactly
+/// instead of reducing it modulo the divisors. `None` if a worry level grows past 4096 bits.
+pub fn oracle(monkeys: &[Monkey], rounds: usize, relief: bool) -> Option<u64> {
+    let mut items: Vec<Vec<Worry>> = monkeys
+        .iter()
+        .map(|m| m.items.iter().map(|&item| Worry::new(item)).collect())
+        .collect();
+    let mut inspected = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let mut item = item.apply(monkey.operation);
+                if relief {
+                    item = item.div(3);
+                }
+                if item.0.len() > ORACLE_DIGITS {
+                    return None;
+                }
+                let target = if item.rem(monkey.test) == 0 {
+                    monkey.if_true_monkey
+                } else {
+                    monkey.if_false_monkey
+                };
+                items[target].push(item);
+                inspected[i] += 1;
+            }
+        }
+    }
+
+    inspected.sort_by_key(|&n| Reverse(n));
+    Some(inspected[..2].iter().product())
+}
+
+/// Notes on `size` monkeys, between two and eight, with distinct prime divisors. Part 1 keeps
+/// its worry levels without any modulo, so only notes under which they fit in a `u64` for its
+/// twenty rounds are kept.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 8);
+    loop {
+        let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut divisors);
+
+        let monkeys: Vec<_> = (0..count)
+            .map(|i| {
+                let items: Vec<u64> = (0..rng.range(0..=6)).map(|_| rng.range(50..=99)).collect();
+                let operation = match rng.below(10) {
+                    0 => None,
+                    1..=4 => Some(('*', rng.range(2..=19))),
+                    _ => Some(('+', rng.range(1..=8))),
+                };
+                let mut others: Vec<usize> = (0..count).filter(|&other| other != i).collect();
+                rng.shuffle(&mut others);
+                let targets = [others[0], *others.get(1).unwrap_or(&others[0])];
+                (items, operation, divisors[i], targets)
+            })
+            .collect();
+
+        // part 1, checking for overflow
+        let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.0.clone()).collect();
+        let fits = (0..20 * count).all(|turn| {
+            let (_, operation, divisor, targets) = monkeys[turn % count];
+            std::mem::take(&mut items[turn % count])
+                .into_iter()
+                .all(|old| {
+                    let new = match operation {
+                        None => old.checked_mul(old),
+                        Some(('*', n)) => old.checked_mul(n),
+                        Some((_, n)) => old.checked_add(n),
+                    };
+                    new.map(|new| items[targets[usize::from(new / 3 % divisor != 0)]].push(new / 3))
+                        .is_some()
+                })
+        });
+        if !fits {
+            continue;
+        }
+
+        let notes: Vec<String> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, (items, operation, divisor, [if_true, if_false]))| {
+                let items: Vec<String> = items.iter().map(|item| format!(" {item}")).collect();
+                let operation = match operation {
+                    None => "old * old".to_owned(),
+                    Some((op, n)) => format!("old {op} {n}"),
+                };
+                format!(
+                    "Monkey {i}:\n  Starting items:{}\n  Operation: new = {operation}\n  Test: divisible by {divisor}\n    If true: throw to monkey {if_true}\n    If false: throw to monkey {if_false}\n",
+                    items.join(",")
+                )
+            })
+            .collect();
+        return notes.join("\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{oracle, parse, part1, part2, part2_after};
+
+    #[test]
+    fn test() {
+        let input = r"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+        let monkeys = parse(input).unwrap();
+        assert_eq!(10605, part1(&monkeys));
+        assert_eq!(2713310158, part2(&monkeys));
+        assert_eq!(Some(10605), oracle(&monkeys, 20, true));
+        assert_eq!(10197, part2_after(&monkeys, 20));
+        assert_eq!(Some(10197), oracle(&monkeys, 20, false));
+        assert_eq!(None, oracle(&monkeys, 1000, false));
+
+        let err = parse(&input.replace("monkey 3", "monkey 4")).err();
+        assert_eq!(
+            Some("day 11, line 6, column 31: expected a monkey below 4, found `4`".to_owned()),
+            err.map(|err| err.to_string())
+        );
+        let err =
+            parse(&input.replace("If false: throw to monkey 0", "If false: throw to monkey 1"));
+        assert_eq!(
+            Some(
+                "day 11, line 13, column 31: expected a monkey other than 1, found `1`".to_owned()
+            ),
+            err.err().map(|err| err.to_string())
+        );
+        let first = &input[..input.find("\n\n").unwrap() + 1];
+        assert_eq!(
+            Some(
+                "day 11, line 7, column 1: expected a second monkey, found end of input".to_owned()
+            ),
+            parse(first).err().map(|err| err.to_string())
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    generate::Rng,
+    grid::Grid,
+    image::Rgb,
+    parse::{Cursor, Lines},
+    point::Point,
+    repl::Session,
+    search::{astar, bfs, Search},
+    Answer, ParseError, Solution,
+};
+
+/// The parsed heightmap: the elevation of every square, from 0 for `a` to 25 for `z`, and where
+/// the climb starts and ends.
+#[derive(Debug, Clone)]
+pub struct Heightmap {
+    pub grid: Grid<u8>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Heightmap {
+    /// The squares next to `pos` that are at most one higher.
+    fn climbs(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        let highest = self.grid[pos] + 1;
+        self.grid
+            .neighbours4(pos)
+            .filter(move |&next| self.grid[next] <= highest)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part2(map).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Heightmap, ParseError> {
+    let mut start = None;
+    let mut end = None;
+
+    let grid = Grid::parse(Day12::DAY, input, |pos, c| match c {
+        'S' if start.is_none() => {
+            start = Some(pos);
+            Ok(0)
+        }
+        'E' if end.is_none() => {
+            end = Some(pos);
+            Ok(25)
+        }
+        'a'..='z' => Ok(c as u8 - b'a'),
+        _ => Err(match (start, end) {
+            (None, None) => "a height `a` to `z`, `S` or `E`",
+            (None, Some(_)) => "a height `a` to `z` or `S`",
+            (Some(_), None) => "a height `a` to `z` or `E`",
+            (Some(_), Some(_)) => "a height `a` to `z`",
+        }
+        .to_owned()),
+    })?;
+
+    // the whole map has been read, so whatever is missing is missing at its end
+    let mut lines = Lines::new(Day12::DAY, input);
+    lines.by_ref().for_each(drop);
+    let start = start.ok_or_else(|| lines.end_of_input("the start `S`"))?;
+    let end = end.ok_or_else(|| lines.end_of_input("the best signal `E`"))?;
+    let map = Heightmap { grid, start, end };
+    // part 2 starts from every lowest square, `S` among them, so one climb is enough for both
+    if find_path(&map, map.start).is_none() {
+        return Err(lines.end_of_input("a climb from `S` to `E`"));
+    }
+    Ok(map)
+}
+
+/// A shortest climb from `start` to the best signal, both included, found with A* guided by how
+/// far away the signal is.
+pub fn find_path(map: &Heightmap, start: Point) -> Option<Vec<Point>> {
+    climb(map, start, map.end)
+}
+
+/// A shortest climb from `from` to `to`, both included.
+pub fn climb(map: &Heightmap, from: Point, to: Point) -> Option<Vec<Point>> {
+    let (_, path) = astar(
+        from,
+        |&pos| pos == to,
+        |&pos| map.climbs(pos).map(|next| (next, 1)),
+        |&pos| pos.manhattan(to) as usize,
+    )?;
+    Some(path)
+}
+
+pub fn part1(map: &Heightmap) -> usize {
+    find_path(map, map.start)
+        .expect("parse checks for a climb")
+        .len()
+        - 1
+}
+
+/// A search from every lowest square at once.
+fn from_lowest(map: &Heightmap) -> Search<Point> {
+    let lowest = map
+        .grid
+        .iter()
+        .filter(|&(_, &e)| e == 0)
+        .map(|(pos, _)| pos);
+    bfs(lowest, |&pos| map.climbs(pos))
+}
+
+pub fn part2(map: &Heightmap) -> usize {
+    from_lowest(map)
+        .distance(&map.end)
+        .expect("parse checks for a climb")
+}
+
+/// The heightmap, to ask for climbs between any two squares.
+pub struct Explorer {
+    map: Heightmap,
+}
+
+impl Explorer {
+    pub fn new(map: Heightmap) -> Self {
+        Explorer { map }
+    }
+
+    /// Reads a square, as `S`, `E` or `x,y`.
+    fn square(&self, line: &mut Cursor) -> Result<Point, ParseError> {
+        let start = *line;
+        let pos = if line.eat("S") {
+            self.map.start
+        } else if line.eat("E") {
+            self.map.end
+        } else {
+            Point::parse_pair(line).map_err(|_| start.error("`S`, `E` or `x,y`"))?
+        };
+        match self.map.grid.contains(pos) {
+            true => Ok(pos),
+            false => Err(start.error("a square of the map")),
+        }
+    }
+}
+
+impl Session for Explorer {
+    fn help(&self) -> &'static [&'static str] {
+        &[
+            "path <from> <to>: the fewest steps climbing from a square to another, each `S`, `E` \
+           or `x,y`, like `path 3,4 E`",
+        ]
+    }
+
+    fn query(&self, line: &mut Cursor) -> Result<String, ParseError> {
+        line.literal("path ")?;
+        let from = self.square(line)?;
+        line.literal(" ")?;
+        let to = self.square(line)?;
+        line.end()?;
+        Ok(match climb(&self.map, from, to) {
+            Some(path) => format!("{} steps", path.len() - 1),
+            None => "no climb".to_owned(),
+        })
+    }
+}
+
+/// The heightmap a pixel per square, from dark for `a` to light for `z`, with the shortest climb
+/// from the start, or from the lowest squares in part 2, in red.
+pub fn image(map: &Heightmap, part2: bool) -> Grid<Rgb> {
+    let mut image = map
+        .grid
+        .map(|&elevation| Rgb(20, 30, 60).mix(Rgb(230, 230, 210), elevation as usize, 25));
+    let path = if part2 {
+        from_lowest(map).path(&map.end)
+    } else {
+        find_path(map, map.start)
+    };
+    for pos in path.into_iter().flatten() {
+        image[pos] = Rgb(220, 30, 30);
+    }
+    image[map.start] = Rgb(255, 200, 0);
+    image[map.end] = Rgb(0, 220, 255);
+    image
+}
+
+/// A heightmap `size` squares wide, at least 26, with a climb from `S` to `E` that never goes
+/// up more than one step at a time.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = rng.range(5..=(width / 2).max(5));
+    let mut grid: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.range(b'a'..=b'z')).collect())
+        .collect();
+
+    // a path left to right, wandering up and down in every column
+    let mut path = vec![(0, rng.below(height))];
+    for x in 0..width {
+        let (_, mut y) = *path.last().unwrap();
+        let target = rng.below(height);
+        while y != target {
+            y = if y < target { y + 1 } else { y - 1 };
+            path.push((x, y));
+        }
+        if x + 1 < width {
+            path.push((x + 1, y));
+        }
+    }
+
+    // climbing one letter at 25 random steps of the path
+    let mut climbs: Vec<usize> = (1..path.len()).collect();
+    rng.shuffle(&mut climbs);
+    let mut climbs = climbs[..25].to_vec();
+    climbs.sort_unstable();
+    let mut elevation = b'a';
+    for (i, &(x, y)) in path.iter().enumerate() {
+        if climbs.binary_search(&i).is_ok() {
+            elevation += 1;
+        }
+        grid[y][x] = elevation;
+    }
+    let ((sx, sy), (ex, ey)) = (path[0], path[path.len() - 1]);
+    grid[sy][sx] = b'S';
+    grid[ey][ex] = b'E';
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_path, parse, part1, part2, Explorer};
+    use crate::{parse::Cursor, point::Point, repl::Session};
+
+    #[test]
+    fn test() {
+        let input = r"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+        let map = parse(input).unwrap();
+        assert_eq!(31, part1(&map));
+        assert_eq!(29, part2(&map));
+
+        let path = find_path(&map, Point::new(0, 4)).unwrap();
+        assert_eq!(
+            (Point::new(0, 4), map.end),
+            (path[0], *path.last().unwrap())
+        );
+        assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+        assert_eq!(30, path.len());
+
+        let explorer = Explorer::new(map);
+        let path = |command| {
+            explorer
+                .query(&mut Cursor::new(12, 1, command))
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(Ok("31 steps".to_owned()), path("path S E"));
+        assert_eq!(Ok("29 steps".to_owned()), path("path 0,4 E"));
+        assert_eq!(Ok("0 steps".to_owned()), path("path 3,4 3,4"));
+        assert_eq!(
+            Err("day 12, line 1, column 8: expected a square of the map, found `8`".to_owned()),
+            path("path S 8,0")
+        );
+        assert!(path("path S").is_err());
+
+        // a map that parses has a climb, so neither part panics
+        assert_eq!(
+            "day 12, line 2, column 1: expected a climb from `S` to `E`, found end of input",
+            parse("SbE").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use std::{cmp::Ordering, fmt::Debug, slice, str::FromStr};
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, Lines},
+    repl::Session,
+    trace::trace,
+    Answer, ParseError, Solution,
+};
+
+#[derive(PartialEq, Eq, Clone)]
+pub enum Term {
+    Item(usize),
+    List(Vec<Term>),
+}
+
+/// How deep lists may nest, so that hostile input cannot overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+impl Term {
+    fn parse_helper(line: &mut Cursor, depth: usize) -> Result<Self, ParseError> {
+        if line.peek() == Some('[') && depth == MAX_DEPTH {
+            Err(line.error(format!("a number, as lists nest at most {MAX_DEPTH} deep")))
+        } else if line.eat("[") {
+            let mut items = Vec::new();
+            if line.eat("]") {
+                return Ok(Term::List(items));
+            }
+            loop {
+                items.push(Self::parse_helper(line, depth + 1)?);
+                if line.eat("]") {
+                    break;
+                }
+                if !line.eat(",") {
+                    return Err(line.error("`,` or `]`"));
+                }
+            }
+            Ok(Term::List(items))
+        } else if line.peek().is_some_and(|c| c.is_ascii_digit()) {
+            line.number().map(Term::Item)
+        } else {
+            Err(line.error("a number or `[`"))
+        }
+    }
+
+    /// Parses a packet taking up the rest of the line.
+    pub fn parse(line: &mut Cursor) -> Result<Self, ParseError> {
+        let term = Self::parse_helper(line, 0)?;
+        line.end()?;
+        Ok(term)
+    }
+
+    /// The order of the puzzle, where an integer compared to a list is promoted to a list
+    /// holding it. Different packets can be equal in it, like `1` and `[[1]]`.
+    pub fn packet_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Term::Item(a), Term::Item(b)) => a.cmp(b),
+            (Term::List(left), Term::List(right)) => Self::list_cmp(left, right),
+            (Term::Item(_), Term::List(right)) => Self::list_cmp(slice::from_ref(self), right),
+            (Term::List(left), Term::Item(_)) => Self::list_cmp(left, slice::from_ref(other)),
+        }
+    }
+
+    fn list_cmp(left: &[Term], right: &[Term]) -> Ordering {
+        left.iter()
+            .zip(right)
+            .map(|(a, b)| a.packet_cmp(b))
+            .find(|result| result.is_ne())
+            .unwrap_or_else(|| left.len().cmp(&right.len()))
+    }
+}
+
+impl FromStr for Term {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Term::parse(&mut Cursor::new(Day13::DAY, 1, s))
+    }
+}
+
+impl Debug for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Term::Item(i) => write!(f, "{}", i),
+            Term::List(items) => {
+                write!(f, "[")?;
+                for (idx, item) in items.iter().enumerate() {
+                    write!(f, "{:?}", item)?;
+                    if idx != items.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// The puzzle's order, with packets it finds equal told apart by their structure (integers
+/// before lists), so that it agrees with `==`.
+impl Ord for Term {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.packet_cmp(other).then_with(|| match (self, other) {
+            (Term::Item(a), Term::Item(b)) => a.cmp(b),
+            (Term::List(left), Term::List(right)) => left.cmp(right),
+            (Term::Item(_), Term::List(_)) => Ordering::Less,
+            (Term::List(_), Term::Item(_)) => Ordering::Greater,
+        })
+    }
+}
+
+impl PartialOrd for Term {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<(Term, Term)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        part1(pairs).into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        part2(pairs).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Term, Term)>, ParseError> {
+    let mut lines = Lines::new(Day13::DAY, input);
+    let mut pairs = Vec::new();
+    loop {
+        let a = Term::parse(&mut lines.expect_line("a packet")?)?;
+        let b = Term::parse(&mut lines.expect_line("a packet")?)?;
+        pairs.push((a, b));
+
+        match lines.next() {
+            Some(line) => line.end()?,
+            None => break,
+        }
+    }
+    Ok(pairs)
+}
+
+pub fn part1(pairs: &[(Term, Term)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, (a, b))| {
+            let in_order = a.packet_cmp(b).is_lt();
+            trace!(Day13::DAY, "pair", index = idx + 1, in_order = in_order);
+            in_order.then_some(idx + 1)
+        })
+        .sum()
+}
+
+pub fn part2(pairs: &[(Term, Term)]) -> usize {
+    let mut input: Vec<_> = pairs
+        .iter()
+        .flat_map(|(a, b)| [a.clone(), b.clone()])
+        .collect();
+    input.extend([
+        Term::List(vec![Term::List(vec![Term::Item(2)])]),
+        Term::List(vec![Term::List(vec![Term::Item(6)])]),
+    ]);
+    input.sort();
+
+    let mut key = 1;
+    let mut found = false;
+    for (idx, term) in input.iter().enumerate() {
+        if term == &Term::List(vec![Term::List(vec![Term::Item(2)])])
+            || term == &Term::List(vec![Term::List(vec![Term::Item(6)])])
+        {
+            key *= idx + 1;
+            if found {
+                break;
+            }
+            found = true;
+        }
+    }
+
+    key
+}
+
+/// The pairs of packets, though comparing packets needs nothing from them.
+pub struct Explorer {
+    pairs: Vec<(Term, Term)>,
+}
+
+impl Explorer {
+    pub fn new(pairs: Vec<(Term, Term)>) -> Self {
+        Explorer { pairs }
+    }
+}
+
+impl Session for Explorer {
+    fn help(&self) -> &'static [&'static str] {
+        &[
+            "cmp <left> <right>: whether two packets are in the right order, like \
+             `cmp [1,[2]] [[1],2]`",
+            "pair <n>: the n-th pair of the input and whether it is in the right order",
+        ]
+    }
+
+    fn query(&self, line: &mut Cursor) -> Result<String, ParseError> {
+        fn order(left: &Term, right: &Term) -> &'static str {
+            match left.packet_cmp(right) {
+                Ordering::Less => "the right order",
+                Ordering::Equal => "neither order",
+                Ordering::Greater => "the wrong order",
+            }
+        }
+
+        match line.one_of(&["cmp ", "pair "])? {
+            "cmp " => {
+                let left = Term::parse_helper(line, 0)?;
+                line.literal(" ")?;
+                let right = Term::parse(line)?;
+                Ok(order(&left, &right).to_owned())
+            }
+            _ => {
+                let start = *line;
+                let n: usize = line.number()?;
+                line.end()?;
+                match n.checked_sub(1).and_then(|i| self.pairs.get(i)) {
+                    Some((left, right)) => {
+                        Ok(format!("{left:?} {right:?}: {}", order(left, right)))
+                    }
+                    None => Err(start.error(format!("a pair from 1 to {}", self.pairs.len()))),
+                }
+            }
+        }
+    }
+}
+
+/// `size` pairs of packets, nested up to four lists deep.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items: Vec<String> = (0..rng.below(6))
+            .map(|_| {
+                if depth < 4 && rng.chance(1, 3) {
+                    packet(rng, depth + 1)
+                } else {
+                    rng.range(0..=10).to_string()
+                }
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .collect();
+    pairs.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, str::FromStr};
+
+    use super::{parse, part1, part2, Explorer, Term, MAX_DEPTH};
+    use crate::{generate::Rng, parse::Cursor, repl::Session};
+    static TEST_INPUT: &str = r"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+    #[test]
+    fn test() {
+        // debugging
+        assert_eq!(
+            TEST_INPUT,
+            TEST_INPUT
+                .split("\n\n")
+                .map(|pair| {
+                    let mut pair = pair.lines();
+                    let a = Term::from_str(pair.next().unwrap()).unwrap();
+                    let b = Term::from_str(pair.next().unwrap()).unwrap();
+                    format!("{:?}\n{:?}\n", a, b)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        let pairs = parse(TEST_INPUT).unwrap();
+        assert_eq!(13, part1(&pairs));
+        assert_eq!(140, part2(&pairs));
+
+        let explorer = Explorer::new(pairs);
+        let ask = |command| {
+            explorer
+                .query(&mut Cursor::new(13, 1, command))
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(Ok("the right order".to_owned()), ask("cmp [1,[2]] [[1],3]"));
+        assert_eq!(Ok("the wrong order".to_owned()), ask("cmp [1,[2]] [[1],1]"));
+        assert_eq!(Ok("neither order".to_owned()), ask("cmp [1,[2]] [[1],2]"));
+        assert_eq!(
+            Ok("[9] [[8,7,6]]: the wrong order".to_owned()),
+            ask("pair 3")
+        );
+        assert_eq!(
+            Err("day 13, line 1, column 17: expected `,` or `]`, found ` `".to_owned()),
+            ask("cmp [1,[2]] [[1] 2]")
+        );
+        assert!(ask("pair 9").is_err());
+    }
+
+    /// A packet with small integers, so that comparisons often tie and promote.
+    fn term(rng: &mut Rng, depth: usize) -> Term {
+        if depth < 4 && rng.chance(1, 2) {
+            Term::List((0..rng.below(4)).map(|_| term(rng, depth + 1)).collect())
+        } else if rng.chance(1, 20) {
+            Term::Item(usize::MAX)
+        } else {
+            Term::Item(rng.range(0..=3))
+        }
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(13);
+
+        for _ in 0..2000 {
+            let a = term(&mut rng, 0);
+            assert_eq!(Ok(&a), Term::from_str(&format!("{a:?}")).as_ref());
+        }
+
+        // `cmp` is a total order agreeing with `==`, `packet_cmp` a total preorder
+        for _ in 0..20000 {
+            let [a, b, c] = [(); 3].map(|_| term(&mut rng, 0));
+            for cmp in [Term::cmp, Term::packet_cmp] {
+                assert_eq!(cmp(&a, &b), cmp(&b, &a).reverse(), "{a:?} {b:?}");
+                if cmp(&a, &b).is_le() && cmp(&b, &c).is_le() {
+                    assert!(cmp(&a, &c).is_le(), "{a:?} {b:?} {c:?}");
+                }
+            }
+            assert_eq!(a == b, a.cmp(&b) == Ordering::Equal, "{a:?} {b:?}");
+            assert!(a.cmp(&b) == a.packet_cmp(&b) || a.packet_cmp(&b).is_eq());
+        }
+        let mut terms: Vec<Term> = (0..200).map(|_| term(&mut rng, 0)).collect();
+        terms.sort();
+        for (i, a) in terms.iter().enumerate() {
+            assert!(terms[i..].iter().all(|b| a <= b));
+        }
+
+        // anything else is an error, never a panic
+        let alphabet = b"[[[]]],,0123456789 -x\n\xff";
+        for _ in 0..5000 {
+            let bytes: Vec<u8> = (0..rng.below(40))
+                .map(|_| {
+                    if rng.chance(1, 10) {
+                        rng.range(0..=255)
+                    } else {
+                        *rng.choose(alphabet)
+                    }
+                })
+                .collect();
+            let text = String::from_utf8_lossy(&bytes);
+            let _ = Term::from_str(&text);
+            let _ = parse(&text);
+        }
+        let deep = "[".repeat(100000) + &"]".repeat(100000);
+        assert_eq!(
+            format!("expected a number, as lists nest at most {MAX_DEPTH} deep, found `[`"),
+            Term::from_str(&deep)
+                .unwrap_err()
+                .to_string()
+                .split(": ")
+                .nth(1)
+                .unwrap()
+        );
+        assert!(Term::from_str(&"[".repeat(MAX_DEPTH)).is_err());
+        assert!(Term::from_str(&("[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH))).is_ok());
+        assert!(Term::from_str("[18446744073709551616]").is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    animate::Simulation,
+    generate::Rng,
+    grid::Grid,
+    image::Rgb,
+    parse::Lines,
+    point::{Direction8, Point},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Material {
+    Rock,
+    Sand,
+    Air,
+}
+
+impl Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Material::Rock => '#',
+            Material::Sand => 'o',
+            Material::Air => '.',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// Where the sand pours into the cave from.
+pub const SOURCE: Point = Point::new(500, 0);
+
+/// The scanned rock, and the depth of the lowest rock. The grid is wide enough for all the sand
+/// part 2 piles up on the floor, which under a deep cave reaches left of `x = 0`, so the cave is
+/// indexed by the scanned points rather than the grid's.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    pub grid: Grid<Material>,
+    pub max: isize,
+    /// The `x` of the grid's first column.
+    pub left: isize,
+}
+
+impl Cave {
+    /// Where `point` is in the grid.
+    pub fn at(&self, point: Point) -> Point {
+        point - Point::new(self.left, 0)
+    }
+
+    /// The material at `point`, `None` outside the grid.
+    pub fn get(&self, point: Point) -> Option<&Material> {
+        self.grid.get(self.at(point))
+    }
+}
+
+impl Index<Point> for Cave {
+    type Output = Material;
+
+    fn index(&self, point: Point) -> &Material {
+        &self.grid[self.at(point)]
+    }
+}
+
+impl IndexMut<Point> for Cave {
+    fn index_mut(&mut self, point: Point) -> &mut Material {
+        let at = self.at(point);
+        &mut self.grid[at]
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cave: &Self::Input) -> Answer {
+        part1(cave).into()
+    }
+
+    fn part2(cave: &Self::Input) -> Answer {
+        part2(cave).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Cave, ParseError> {
+    let mut lines = Lines::new(Day14::DAY, input);
+    let mut paths = Vec::new();
+
+    for mut line in lines.by_ref() {
+        let mut coords: Vec<Point> = Vec::new();
+        loop {
+            let start = line;
+            let point = Point::parse_pair(&mut line)?;
+            // the sand pours in at `y = 0`, so it cannot reach rock above it
+            if point.y < 0 {
+                return Err(start.error("a point no higher than the source at `500,0`"));
+            }
+            if let Some(last) = coords.last() {
+                if last.x != point.x && last.y != point.y {
+                    return Err(start.error("a point in line with the previous one"));
+                }
+            }
+            coords.push(point);
+
+            if line.is_empty() {
+                break;
+            }
+            line.literal(" -> ")?;
+        }
+        paths.push(coords);
+    }
+
+    let points = paths.iter().flatten();
+    let max = points
+        .clone()
+        .map(|point| point.y)
+        .max()
+        .ok_or_else(|| lines.end_of_input("a rock path"))?;
+    // the sand on the floor spreads as far to either side of the source as the floor is deep
+    let xs = points.map(|point| point.x);
+    let left = xs.clone().min().unwrap().min(SOURCE.x - max - 2) - 1;
+    let right = xs.max().unwrap().max(SOURCE.x + max + 2) + 1;
+    let mut cave = Cave {
+        grid: Grid::new((right - left + 1) as usize, max as usize + 3, Material::Air),
+        max,
+        left,
+    };
+
+    for coords in paths {
+        cave[coords[0]] = Material::Rock;
+        for window in coords.windows(2) {
+            let mut point = window[0];
+            while point != window[1] {
+                point = point.step_towards(window[1]);
+                cave[point] = Material::Rock;
+            }
+        }
+    }
+
+    Ok(cave)
+}
+
+/// Where a unit of sand at `sand` falls next: down if it can, else down and to the left, else
+/// down and to the right. `None` once it has come to rest.
+fn fall(cave: &Cave, sand: Point) -> Option<Point> {
+    [Direction8::S, Direction8::SW, Direction8::SE]
+        .into_iter()
+        .map(|direction| sand + direction.offset())
+        .find(|&next| cave.get(next) == Some(&Material::Air))
+}
+
+/// Drops a unit of sand from the source, returning where it comes to rest on `floor`, or `None`
+/// if it falls past it.
+fn drop_sand(cave: &Cave, floor: isize) -> Option<Point> {
+    let mut sand = SOURCE;
+    while let Some(next) = fall(cave, sand) {
+        sand = next;
+        if sand.y == floor {
+            return None;
+        }
+    }
+    Some(sand)
+}
+
+/// The cave once no more sand comes to rest, because it falls past the rock in part 1 or
+/// because it blocks the source in part 2, and how many units came to rest.
+pub fn settle(cave: &Cave, part2: bool) -> (Cave, usize) {
+    let (mut cave, floor) = if part2 {
+        (with_floor(cave), cave.max + 2)
+    } else {
+        (cave.clone(), cave.max)
+    };
+    let mut count = 0;
+
+    while let Some(rest) = drop_sand(&cave, floor) {
+        cave[rest] = Material::Sand;
+        count += 1;
+        if rest == SOURCE {
+            break;
+        }
+    }
+
+    (cave, count)
+}
+
+pub fn part1(cave: &Cave) -> usize {
+    settle(cave, false).1
+}
+
+pub fn part2(cave: &Cave) -> usize {
+    settle(cave, true).1
+}
+
+/// The cave a pixel per position once the sand has settled, with the source in red.
+pub fn image(cave: &Cave, part2: bool) -> Grid<Rgb> {
+    let (cave, _) = settle(cave, part2);
+    let mut image = cave.grid.map(|material| match material {
+        Material::Rock => Rgb(110, 110, 120),
+        Material::Sand => Rgb(230, 190, 90),
+        Material::Air => Rgb(15, 15, 20),
+    });
+    image[cave.at(SOURCE)] = Rgb(230, 40, 40);
+    image
+}
+
+/// The cave with the floor of part 2, two below the lowest rock, as the bottom row of the grid.
+fn with_floor(cave: &Cave) -> Cave {
+    let mut floored = cave.clone();
+    for x in 0..cave.grid.width() {
+        floored.grid[Point::new(x as isize, cave.max + 2)] = Material::Rock;
+    }
+    floored
+}
+
+/// The sand falling one unit at a time, a step for every move of the falling unit, drawn like
+/// the puzzle's pictures with the source as `+` and the falling unit as `~`.
+pub struct Animation {
+    cave: Cave,
+    /// The depth where sand falls past the rock in part 1, the floor in part 2.
+    floor: isize,
+    part2: bool,
+    falling: Option<Point>,
+    count: usize,
+    done: bool,
+}
+
+impl Animation {
+    pub fn new(cave: &Cave, part2: bool) -> Self {
+        Animation {
+            cave: if part2 {
+                with_floor(cave)
+            } else {
+                cave.clone()
+            },
+            floor: if part2 { cave.max + 2 } else { cave.max },
+            part2,
+            falling: None,
+            count: 0,
+            done: false,
+        }
+    }
+}
+
+impl Simulation for Animation {
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let Some(sand) = self.falling else {
+            self.falling = Some(SOURCE);
+            return true;
+        };
+        match fall(&self.cave, sand) {
+            Some(next) if next.y == self.floor => {
+                self.falling = None;
+                self.done = true;
+            }
+            Some(next) => self.falling = Some(next),
+            None => {
+                self.cave[sand] = Material::Sand;
+                self.count += 1;
+                self.falling = None;
+                self.done = sand == SOURCE;
+            }
+        }
+        true
+    }
+
+    fn cell(&self, pos: Point) -> char {
+        match self.cave.get(pos) {
+            _ if self.falling == Some(pos) => '~',
+            Some(Material::Air) if pos == SOURCE => '+',
+            Some(material) => material.to_string().chars().next().unwrap(),
+            None => ' ',
+        }
+    }
+
+    /// The falling unit, or the source between two units.
+    fn focus(&self) -> Point {
+        self.falling.unwrap_or(SOURCE)
+    }
+
+    fn status(&self) -> String {
+        let end = match (self.done, self.part2) {
+            (false, _) => "",
+            (true, false) => ", the next falls forever",
+            (true, true) => ", the source is blocked",
+        };
+        format!("{} units of sand at rest{end}", self.count)
+    }
+}
+
+/// `size` paths of rock below the source of the sand, each of up to five straight lines. Only
+/// caves where the sand eventually falls past the rock are kept, as part 1 needs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let spread = (10 + size).min(490);
+    let depth = 10 + size / 2;
+    loop {
+        let paths: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (rng.range(500 - spread..=500 + spread), rng.range(1..=depth));
+                let mut points = vec![format!("{x},{y}")];
+                for _ in 0..rng.range(1..=5) {
+                    let len = rng.range(1..=8);
+                    if rng.chance(1, 2) {
+                        x = if x + len <= 500 + spread {
+                            x + len
+                        } else {
+                            x - len
+                        };
+                    } else {
+                        y = if y + len <= depth {
+                            y + len
+                        } else {
+                            y.saturating_sub(len).max(1)
+                        };
+                    }
+                    points.push(format!("{x},{y}"));
+                }
+                points.join(" -> ") + "\n"
+            })
+            .collect();
+        let input = paths.concat();
+
+        let mut cave = parse(&input).unwrap();
+        let spills = loop {
+            match drop_sand(&cave, cave.max) {
+                None => break true,
+                Some(SOURCE) => break false,
+                Some(rest) => cave[rest] = Material::Sand,
+            }
+        };
+        if spills {
+            return input;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2, with_floor, Material, SOURCE};
+    use crate::{allocations::measure, point::Point};
+    static TEST_INPUT: &str = r"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    #[test]
+    fn test() {
+        let cave = parse(TEST_INPUT).unwrap();
+        assert_eq!(24, part1(&cave));
+        assert_eq!(93, part2(&cave));
+        // the only allocation is the copy of the cave the sand piles up in
+        let (_, allocations) = measure(|| part1(&cave));
+        assert_eq!(1, allocations.unwrap().count);
+        // deeper than the source is right of `x = 0`, the floor's sand piles up left of it
+        let deep = parse("1000,600 -> 1000,600").unwrap();
+        assert_eq!(0, part1(&deep));
+        let floored = with_floor(&deep);
+        assert!((SOURCE.x - 602..=SOURCE.x + 602)
+            .all(|x| floored.get(Point::new(x, 602)) == Some(&Material::Rock)));
+
+        let err = parse("0,4 -> 0,-1").unwrap_err();
+        assert_eq!(
+            "day 14, line 1, column 8: expected a point no higher than the source at `500,0`, \
+             found `0`",
+            err.to_string()
+        );
+        // rock left of `x = 0` is as good as any other
+        let left = parse("-1,4 -> -3,4").unwrap();
+        assert_eq!(0, part1(&left));
+        assert_eq!(36, part2(&left));
+    }
+}
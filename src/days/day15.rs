@@ -0,0 +1,326 @@
+use std::{
+    cmp::{max, min},
+    collections::BTreeSet,
+};
+
+use crate::{
+    generate::Rng,
+    image::Svg,
+    intervals::Intervals,
+    parse::{Cursor, Lines},
+    point::{Direction8, Point},
+    repl::Session,
+    trace::trace,
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Scan {
+    pub sensor: Point,
+    pub beacon: Point,
+}
+
+impl Scan {
+    /// How far the sensor can tell there is no other beacon.
+    pub fn radius(&self) -> isize {
+        self.sensor.manhattan(self.beacon)
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Scan>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(scans: &Self::Input) -> Answer {
+        part1(scans, 2000000).into()
+    }
+
+    fn part2(scans: &Self::Input) -> Answer {
+        part2(scans, 4000000).expect("no free position").into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Scan>, ParseError> {
+    Lines::new(Day15::DAY, input)
+        .map(|mut line| {
+            line.literal("Sensor at ")?;
+            let sensor = Point::parse_labelled(&mut line)?;
+            line.literal(": closest beacon is at ")?;
+            let beacon = Point::parse_labelled(&mut line)?;
+            line.end()?;
+
+            Ok(Scan { sensor, beacon })
+        })
+        .collect()
+}
+
+pub fn part1(scans: &[Scan], row: isize) -> isize {
+    let (acc, set) = scans.iter().fold(
+        (Intervals::new(), BTreeSet::new()),
+        |(mut acc, mut set), scan| {
+            let (sensor, beacon) = (scan.sensor, scan.beacon);
+            let dx = scan.radius() - (row - sensor.y).abs();
+
+            if dx >= 0 {
+                acc.add(sensor.x - dx..sensor.x + dx + 1);
+            }
+            if beacon.y == row {
+                set.insert(beacon.x);
+            }
+
+            (acc, set)
+        },
+    );
+
+    for range in acc.ranges() {
+        trace!(
+            Day15::DAY,
+            "interval",
+            row = row,
+            from = range.start,
+            to = range.end - 1,
+        );
+    }
+    acc.len() - set.len() as isize
+}
+
+/// The tuning frequency of the one position in the search area no sensor covers, `None` if the
+/// sensors cover all of it.
+pub fn part2(scans: &[Scan], searching_space: isize) -> Option<u64> {
+    (0..=searching_space)
+        .filter_map(|y| {
+            scans
+                .iter()
+                .fold(Intervals::new(), |mut acc, scan| {
+                    let dx = scan.radius() - (y - scan.sensor.y).abs();
+                    let lo = max(0, scan.sensor.x - dx);
+                    let hi = min(searching_space, scan.sensor.x + dx);
+                    if lo <= hi {
+                        acc.add(lo..hi + 1);
+                    }
+                    acc
+                })
+                .complement(0..searching_space + 1)
+                .ranges()
+                .first()
+                .map(|free| 4000000 * free.start as u64 + y as u64)
+        })
+        .next()
+}
+
+/// The sensor reports, to count the positions without a beacon on any row.
+pub struct Explorer {
+    scans: Vec<Scan>,
+}
+
+impl Explorer {
+    pub fn new(scans: Vec<Scan>) -> Self {
+        Explorer { scans }
+    }
+}
+
+impl Session for Explorer {
+    fn help(&self) -> &'static [&'static str] {
+        &["row <y>: how many positions of a row cannot hold a beacon, like `row 2000000`"]
+    }
+
+    fn query(&self, line: &mut Cursor) -> Result<String, ParseError> {
+        line.literal("row ")?;
+        let row = line.number()?;
+        line.end()?;
+        Ok(part1(&self.scans, row).to_string())
+    }
+}
+
+/// The ranges of the sensors as diamonds, with the sensors and their closest beacons as dots,
+/// clipped to the cells between the corners of `view`, or showing every range by default.
+pub fn image(scans: &[Scan], view: Option<(Point, Point)>) -> String {
+    let corners = |scan: &Scan| {
+        let radius = Point::new(scan.radius(), scan.radius());
+        (scan.sensor - radius, scan.sensor + radius)
+    };
+    let (min, max) = view.unwrap_or_else(|| {
+        scans
+            .iter()
+            .map(corners)
+            .reduce(|(a, b), (c, d)| {
+                (
+                    Point::new(a.x.min(c.x), a.y.min(c.y)),
+                    Point::new(b.x.max(d.x), b.y.max(d.y)),
+                )
+            })
+            .unwrap_or_default()
+    });
+
+    let mut svg = Svg::new(min, max);
+    let in_view: Vec<&Scan> = scans
+        .iter()
+        .filter(|&scan| {
+            let (min, max) = corners(scan);
+            svg.overlaps(min, max)
+        })
+        .collect();
+    for scan in &in_view {
+        let (sensor, r) = (scan.sensor, scan.radius());
+        svg.polygon(
+            &[
+                (sensor - Point::new(0, r), (0.5, 0.0)),
+                (sensor + Point::new(r, 0), (1.0, 0.5)),
+                (sensor + Point::new(0, r), (0.5, 1.0)),
+                (sensor - Point::new(r, 0), (0.0, 0.5)),
+            ],
+            "fill:#3070c0;fill-opacity:0.3;stroke:#3070c0;stroke-width:0.2%",
+        );
+    }
+    for scan in &in_view {
+        svg.dot(scan.sensor, 3.0, "fill:#103060");
+        svg.dot(scan.beacon, 3.0, "fill:#e0a020");
+    }
+    svg.finish()
+}
+
+/// Part 1 by marking every position of the row that some sensor covers, one by one.
+pub fn oracle_part1(scans: &[Scan], row: isize) -> isize {
+    let lo = scans
+        .iter()
+        .map(|s| s.sensor.x - s.radius())
+        .min()
+        .unwrap_or(0);
+    let hi = scans
+        .iter()
+        .map(|s| s.sensor.x + s.radius())
+        .max()
+        .unwrap_or(0);
+
+    let mut covered = vec![false; (hi - lo + 1) as usize];
+    for scan in scans {
+        for x in lo..=hi {
+            if scan.sensor.manhattan(Point::new(x, row)) <= scan.radius() {
+                covered[(x - lo) as usize] = true;
+            }
+        }
+    }
+    for scan in scans.iter().filter(|scan| scan.beacon.y == row) {
+        covered[(scan.beacon.x - lo) as usize] = false;
+    }
+    covered.iter().filter(|&&covered| covered).count() as isize
+}
+
+/// Part 2 by marking every position of the search area that some sensor covers, which only
+/// works for small areas.
+pub fn oracle_part2(scans: &[Scan], searching_space: isize) -> Option<u64> {
+    let side = searching_space as usize + 1;
+    let mut covered = vec![false; side * side];
+    for scan in scans {
+        for y in 0..=searching_space {
+            for x in 0..=searching_space {
+                if scan.sensor.manhattan(Point::new(x, y)) <= scan.radius() {
+                    covered[y as usize * side + x as usize] = true;
+                }
+            }
+        }
+    }
+    let free = covered.iter().position(|&covered| !covered)?;
+    Some(4000000 * (free % side) as u64 + (free / side) as u64)
+}
+
+/// Sensor reports for the real search area, see [`generate_within`].
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_within(rng, size, 4000000)
+}
+
+/// Sensor reports leaving exactly one position with both coordinates in `0..=bound` uncovered.
+///
+/// Four sensors out along the axes from that position cover the quadrants around it, four more
+/// out along the diagonals cover the lines between the quadrants, and `size` more are scattered
+/// around without reaching it.
+pub fn generate_within(rng: &mut Rng, size: usize, bound: isize) -> String {
+    let free = Point::new(rng.range(0..=bound), rng.range(0..=bound));
+    let mut sensors = Vec::new();
+
+    let axis = rng.range(bound + 1..=2 * bound + 1);
+    let diagonal = rng.range(bound / 2 + 1..=bound + 1);
+    for direction in [Direction8::E, Direction8::S, Direction8::W, Direction8::N] {
+        sensors.push((free + direction.offset() * axis, axis - 1));
+    }
+    for direction in [
+        Direction8::SE,
+        Direction8::NE,
+        Direction8::SW,
+        Direction8::NW,
+    ] {
+        sensors.push((free + direction.offset() * diagonal, 2 * diagonal - 1));
+    }
+    while sensors.len() < 8 + size {
+        let sensor = Point::new(rng.range(0..=bound), rng.range(0..=bound));
+        let distance = sensor.manhattan(free);
+        if distance > 0 {
+            sensors.push((sensor, rng.range(0..=distance - 1)));
+        }
+    }
+    rng.shuffle(&mut sensors);
+
+    sensors
+        .into_iter()
+        .map(|(sensor, radius)| {
+            // the closest beacon is anywhere on the edge of the sensor's range
+            let dx = rng.range(-radius..=radius);
+            let rest = radius - dx.abs();
+            let dy = if rng.chance(1, 2) { rest } else { -rest };
+            let (s, b) = (sensor, sensor + Point::new(dx, dy));
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s.x, s.y, b.x, b.y
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{oracle_part1, oracle_part2, parse, part1, part2, Explorer};
+    use crate::{parse::Cursor, repl::Session};
+    static TEST_INPUT: &str = r"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+    #[test]
+    fn test() {
+        let scans = parse(TEST_INPUT).unwrap();
+        assert_eq!(26, part1(&scans, 10));
+        assert_eq!(Some(56000011), part2(&scans, 20));
+        assert_eq!(26, oracle_part1(&scans, 10));
+        assert_eq!(Some(56000011), oracle_part2(&scans, 20));
+
+        let explorer = Explorer::new(scans);
+        let row = |command| {
+            explorer
+                .query(&mut Cursor::new(15, 1, command))
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(Ok("26".to_owned()), row("row 10"));
+        assert_eq!(Ok("0".to_owned()), row("row -100"));
+        assert!(row("row ten").is_err());
+
+        // a sensor covering the whole search area leaves nowhere for the beacon
+        let covered = parse("Sensor at x=10, y=10: closest beacon is at x=30, y=10").unwrap();
+        assert_eq!(None, part2(&covered, 20));
+        assert_eq!(None, oracle_part2(&covered, 20));
+    }
+}
@@ -0,0 +1,581 @@
+use std::{collections::HashMap, iter};
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, Lines},
+    repl::Session,
+    search::all_pairs_bfs,
+    trace::{self, trace},
+    Answer, ParseError, Solution,
+};
+
+/// Minutes to walk from one valve to another, by name.
+pub type DistanceMatrix = HashMap<String, HashMap<String, i32>>;
+
+fn find_path<'a>(
+    to_open: &[&'a Valve],
+    distances: &'a DistanceMatrix,
+    start: &'a Valve,
+    minutes: i32,
+    path: &[&'a Valve],
+    no_overlap: &[&'a Valve],
+) -> PathFit<'a> {
+    let mut paths: Vec<PathFit> = Vec::new();
+
+    for valve in to_open {
+        if no_overlap.contains(valve) {
+            continue;
+        }
+
+        let distance = distances[&start.name][&valve.name];
+        if distance >= minutes {
+            continue;
+        }
+        let minutes_left = minutes - distance - 1;
+        let flow = valve.flow * minutes_left;
+        let next_to_open: Vec<_> = to_open.iter().filter(|v| *v != valve).copied().collect();
+
+        let mut next_path = path.to_vec();
+        next_path.push(valve);
+        let full_path = find_path(
+            &next_to_open,
+            distances,
+            valve,
+            minutes_left,
+            &next_path,
+            no_overlap,
+        );
+        let mut add_path = path.to_vec();
+        add_path.extend(full_path.path);
+        paths.push(PathFit {
+            path: add_path,
+            flow: full_path.flow + flow,
+        });
+    }
+
+    let mut best_path = PathFit {
+        path: Vec::new(),
+        flow: 0,
+    };
+    for path_fit in paths {
+        if path_fit.flow > best_path.flow {
+            best_path = path_fit;
+        }
+    }
+    best_path
+}
+
+/// Records the best flow for every set of opened valves (a bitmask over `to_open`) reachable in
+/// time.
+fn best_flows(
+    to_open: &[&Valve],
+    distances: &DistanceMatrix,
+    start: &Valve,
+    minutes: i32,
+    opened: u64,
+    flow: i32,
+    best: &mut HashMap<u64, i32>,
+) {
+    let entry = best.entry(opened).or_insert(0);
+    *entry = (*entry).max(flow);
+
+    for (i, valve) in to_open.iter().enumerate() {
+        if opened & (1 << i) != 0 {
+            continue;
+        }
+
+        let distance = distances[&start.name][&valve.name];
+        if distance >= minutes {
+            continue;
+        }
+        let minutes_left = minutes - distance - 1;
+        best_flows(
+            to_open,
+            distances,
+            valve,
+            minutes_left,
+            opened | (1 << i),
+            flow + valve.flow * minutes_left,
+            best,
+        );
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PathFit<'a> {
+    path: Vec<&'a Valve>,
+    flow: i32,
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Valve {
+    pub name: String,
+    pub flow: i32,
+}
+
+/// The parsed scan, reduced to what the planning needs: travel times and the valves worth opening.
+#[derive(Debug, Clone)]
+pub struct Volcano {
+    pub distances: DistanceMatrix,
+    pub start: Valve,
+    pub valves_to_open: Vec<Valve>,
+}
+
+/// How many valves may have a flow rate, as sets of them are kept as the bits of a `u64`.
+const MAX_FLOWING: usize = 64;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Volcano;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(volcano: &Self::Input) -> Answer {
+        part1(volcano).into()
+    }
+
+    fn part2(volcano: &Self::Input) -> Answer {
+        part2(volcano).into()
+    }
+}
+
+fn valve_name<'a>(line: &mut Cursor<'a>) -> Result<&'a str, ParseError> {
+    let name = line.take_while(|c| c.is_ascii_uppercase());
+    if name.is_empty() {
+        return Err(line.error("a valve name"));
+    }
+    Ok(name)
+}
+
+pub fn parse(input: &str) -> Result<Volcano, ParseError> {
+    let mut lines = Lines::new(Day16::DAY, input);
+    let mut neighbours = HashMap::new();
+    let mut flowing = 0;
+    let mut rates = HashMap::new();
+    let valves: Vec<_> = lines
+        .by_ref()
+        .map(|mut line| {
+            line.literal("Valve ")?;
+            let name = valve_name(&mut line)?.to_string();
+            line.literal(" has flow rate=")?;
+            let rate = line;
+            let flow = line.number::<i32>()?;
+            if flow > 0 {
+                flowing += 1;
+                if flowing > MAX_FLOWING {
+                    return Err(rate.error(format!(
+                        "a flow rate of 0, as at most {MAX_FLOWING} valves can have one"
+                    )));
+                }
+                rates.insert(name.clone(), rate);
+            }
+            line.one_of(&["; tunnels lead to valves ", "; tunnel leads to valve "])?;
+            let mut tunnels = Vec::new();
+            loop {
+                let tunnel = line;
+                tunnels.push((valve_name(&mut line)?, tunnel));
+                if line.is_empty() {
+                    break;
+                }
+                line.literal(", ")?;
+            }
+            neighbours.insert(name.clone(), tunnels);
+
+            Ok(Valve { name, flow })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut adj: HashMap<&Valve, Vec<&Valve>> = HashMap::new();
+    for valve in valves.iter() {
+        let neighbour_list = neighbours.get(&valve.name).unwrap();
+        for (neighbour, tunnel) in neighbour_list {
+            let nvalve = valves
+                .iter()
+                .find(|v| &v.name == neighbour)
+                .ok_or_else(|| tunnel.error("a valve from the scan"))?;
+            adj.entry(valve).or_default().push(nvalve);
+        }
+    }
+
+    let distances = all_pairs_bfs(adj.keys().copied(), |valve| adj[valve].iter().copied())
+        .into_iter()
+        .map(|(from, distances)| {
+            let distances = distances
+                .into_iter()
+                .map(|(to, distance)| (to.name.clone(), distance as i32))
+                .collect();
+            (from.name.clone(), distances)
+        })
+        .collect::<DistanceMatrix>();
+    let start = valves
+        .iter()
+        .find(|v| v.name == "AA")
+        .ok_or_else(|| lines.end_of_input("valve `AA`"))?;
+    let valves_to_open: Vec<_> = valves.iter().filter(|v| v.flow > 0).cloned().collect();
+
+    // the walkers go from `AA` to the valves with flow and on between them
+    for from in iter::once(start).chain(&valves_to_open) {
+        let reachable = &distances[&from.name];
+        if let Some(valve) = valves_to_open
+            .iter()
+            .find(|valve| !reachable.contains_key(&valve.name))
+        {
+            return Err(rates[&valve.name].error(format!(
+                "a flow rate of 0, as `{}` cannot be reached from `{}`",
+                valve.name, from.name
+            )));
+        }
+    }
+
+    Ok(Volcano {
+        start: start.clone(),
+        distances,
+        valves_to_open,
+    })
+}
+
+pub fn part1(volcano: &Volcano) -> i32 {
+    let start = &volcano.start;
+    let valves_to_open: Vec<_> = volcano.valves_to_open.iter().collect();
+
+    let path = find_path(
+        &valves_to_open,
+        &volcano.distances,
+        start,
+        30,
+        &[start],
+        &[],
+    );
+
+    if trace::enabled() {
+        let mut best = HashMap::new();
+        best_flows(
+            &valves_to_open,
+            &volcano.distances,
+            start,
+            30,
+            0,
+            0,
+            &mut best,
+        );
+        if let Some((&set, _)) = best.iter().find(|&(_, &flow)| flow == path.flow) {
+            trace_order(volcano, 1, "you", set, 30, path.flow);
+        }
+    }
+    path.flow
+}
+
+pub fn part2(volcano: &Volcano) -> i32 {
+    let start = &volcano.start;
+    let valves_to_open: Vec<_> = volcano.valves_to_open.iter().collect();
+    let distances = &volcano.distances;
+
+    // the human and the elephant open disjoint sets of valves, so try every such pair
+    let mut best = HashMap::new();
+    best_flows(&valves_to_open, distances, start, 26, 0, 0, &mut best);
+
+    let mut max_flow = 0;
+    let mut sets = (0, 0);
+    for (&human, &human_flow) in best.iter() {
+        for (&elephant, &elephant_flow) in best.iter() {
+            if human & elephant == 0 && human_flow + elephant_flow > max_flow {
+                max_flow = human_flow + elephant_flow;
+                sets = (human, elephant);
+            }
+        }
+    }
+
+    let (human, elephant) = sets;
+    trace_order(volcano, 2, "you", human, 26, best[&human]);
+    trace_order(volcano, 2, "elephant", elephant, 26, best[&elephant]);
+    max_flow
+}
+
+/// Traces the valves of `set` (a bitmask over `valves_to_open`) in the order `who` opens them to
+/// release `flow` in `minutes`, with the minute each one is opened.
+fn trace_order(volcano: &Volcano, part: u8, who: &str, set: u64, minutes: i32, flow: i32) {
+    if !trace::enabled() {
+        return;
+    }
+    let order = opening_order(volcano, &volcano.start, set, minutes, flow).unwrap_or_default();
+    for (valve, minutes_left) in order {
+        trace!(
+            Day16::DAY,
+            "open",
+            part = part,
+            who = who,
+            valve = valve.name,
+            minute = minutes - minutes_left,
+        );
+    }
+}
+
+/// An order to open every valve of `set` in from `from` that releases exactly `flow`, with the
+/// minutes left once each one is open.
+fn opening_order<'a>(
+    volcano: &'a Volcano,
+    from: &Valve,
+    set: u64,
+    minutes: i32,
+    flow: i32,
+) -> Option<Vec<(&'a Valve, i32)>> {
+    if set == 0 {
+        return (flow == 0).then(Vec::new);
+    }
+    volcano
+        .valves_to_open
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| set & (1 << i) != 0)
+        .find_map(|(i, valve)| {
+            let minutes_left = minutes - volcano.distances[&from.name][&valve.name] - 1;
+            let released = valve.flow * minutes_left;
+            if minutes_left <= 0 || released > flow {
+                return None;
+            }
+            let rest = opening_order(
+                volcano,
+                valve,
+                set & !(1 << i),
+                minutes_left,
+                flow - released,
+            );
+            rest.map(|mut rest| {
+                rest.insert(0, (valve, minutes_left));
+                rest
+            })
+        })
+}
+
+/// The travel times between valves.
+pub struct Explorer {
+    volcano: Volcano,
+}
+
+impl Explorer {
+    pub fn new(volcano: Volcano) -> Self {
+        Explorer { volcano }
+    }
+
+    /// Reads the name of a valve of the scan.
+    fn valve<'a>(&self, line: &mut Cursor<'a>) -> Result<&'a str, ParseError> {
+        let start = *line;
+        let name = valve_name(line)?;
+        match self.volcano.distances.contains_key(name) {
+            true => Ok(name),
+            false => Err(start.error("a valve from the scan")),
+        }
+    }
+}
+
+impl Session for Explorer {
+    fn help(&self) -> &'static [&'static str] {
+        &[
+            "dist <from> <to>: the minutes it takes to walk from a valve to another, like \
+           `dist AA JJ`",
+        ]
+    }
+
+    fn query(&self, line: &mut Cursor) -> Result<String, ParseError> {
+        line.literal("dist ")?;
+        let from = self.valve(line)?;
+        line.literal(" ")?;
+        let to = self.valve(line)?;
+        line.end()?;
+        Ok(match self.volcano.distances[from].get(to) {
+            Some(minutes) => format!("{minutes} minutes"),
+            None => "no tunnels lead there".to_owned(),
+        })
+    }
+}
+
+/// Part 1 by trying every order of opening the valves, which only works for a handful of them.
+pub fn oracle_part1(volcano: &Volcano) -> i32 {
+    let all = (1 << volcano.valves_to_open.len()) - 1;
+    best_order(volcano, &volcano.start, all, 30)
+}
+
+/// Part 2 by trying every order of opening every set of valves alone, then every pair of disjoint
+/// sets for the human and the elephant.
+pub fn oracle_part2(volcano: &Volcano) -> i32 {
+    let sets = 1 << volcano.valves_to_open.len();
+    let alone: Vec<i32> = (0..sets)
+        .map(|set| best_order(volcano, &volcano.start, set, 26))
+        .collect();
+
+    let mut max_flow = 0;
+    for human in 0..sets {
+        for elephant in 0..sets {
+            if human & elephant == 0 {
+                max_flow = max_flow.max(alone[human] + alone[elephant]);
+            }
+        }
+    }
+    max_flow
+}
+
+/// The best flow from opening valves of `set` (a bitmask over `valves_to_open`) in any order.
+fn best_order(volcano: &Volcano, from: &Valve, set: usize, minutes: i32) -> i32 {
+    volcano
+        .valves_to_open
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| set & (1 << i) != 0)
+        .map(|(i, valve)| {
+            let minutes_left = minutes - volcano.distances[&from.name][&valve.name] - 1;
+            if minutes_left <= 0 {
+                0
+            } else {
+                valve.flow * minutes_left
+                    + best_order(volcano, valve, set & !(1 << i), minutes_left)
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// A connected scan of `size` valves, at least two, starting from `AA`. Up to fifteen of them
+/// have a flow rate.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| String::from_utf8(vec![a, b]).unwrap()))
+        .filter(|name| name != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(count - 1);
+    names.insert(0, "AA".to_owned());
+
+    // a random spanning tree keeps every valve reachable, then a few shortcuts
+    let mut tunnels = vec![Vec::new(); count];
+    let connect = |a: usize, b: usize, tunnels: &mut Vec<Vec<usize>>| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        let other = rng.below(valve);
+        connect(valve, other, &mut tunnels);
+    }
+    for _ in 0..count / 4 {
+        let (a, b) = (rng.below(count), rng.below(count));
+        connect(a, b, &mut tunnels);
+    }
+
+    let mut flows = vec![0; count];
+    let mut with_flow: Vec<usize> = (1..count).collect();
+    rng.shuffle(&mut with_flow);
+    for &valve in with_flow.iter().take(((count - 1) / 3).clamp(1, 15)) {
+        flows[valve] = rng.range(1..=25);
+    }
+
+    (0..count)
+        .map(|valve| {
+            let mut neighbours: Vec<&str> =
+                tunnels[valve].iter().map(|&n| names[n].as_str()).collect();
+            rng.shuffle(&mut neighbours);
+            let (name, flow) = (&names[valve], flows[valve]);
+            if neighbours.len() == 1 {
+                format!(
+                    "Valve {name} has flow rate={flow}; tunnel leads to valve {}\n",
+                    neighbours[0]
+                )
+            } else {
+                format!(
+                    "Valve {name} has flow rate={flow}; tunnels lead to valves {}\n",
+                    neighbours.join(", ")
+                )
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{oracle_part1, oracle_part2, parse, part1, part2, Explorer};
+    use crate::{parse::Cursor, repl::Session, trace};
+    static TEST_INPUT: &str = r"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+    #[test]
+    fn test() {
+        let volcano = parse(TEST_INPUT).unwrap();
+        assert_eq!(1651, part1(&volcano));
+        assert_eq!(1707, part2(&volcano));
+        assert_eq!(1651, oracle_part1(&volcano));
+        assert_eq!(1707, oracle_part2(&volcano));
+
+        let (_, lines) = trace::capture(|| part1(&volcano));
+        let opened: Vec<&str> = lines
+            .lines()
+            .map(|line| line.split_once(" valve=").unwrap().1)
+            .collect();
+        assert_eq!(
+            vec![
+                "DD minute=2",
+                "BB minute=5",
+                "JJ minute=9",
+                "HH minute=17",
+                "EE minute=21",
+                "CC minute=24"
+            ],
+            opened
+        );
+
+        let explorer = Explorer::new(volcano);
+        let dist = |command| {
+            explorer
+                .query(&mut Cursor::new(16, 1, command))
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(Ok("2 minutes".to_owned()), dist("dist AA JJ"));
+        assert_eq!(Ok("6 minutes".to_owned()), dist("dist JJ GG"));
+        assert_eq!(Ok("0 minutes".to_owned()), dist("dist CC CC"));
+        assert_eq!(
+            Err("day 16, line 1, column 9: expected a valve from the scan, found `Z`".to_owned()),
+            dist("dist AA ZZ")
+        );
+        let many: String = (b'B'..=b'D')
+            .flat_map(|a| (b'A'..=b'Z').map(move |b| [a, b]))
+            .take(65)
+            .map(|name| {
+                let name = String::from_utf8_lossy(&name).into_owned();
+                format!("Valve {name} has flow rate=1; tunnel leads to valve AA\n")
+            })
+            .collect();
+        let err = parse(&many).unwrap_err();
+        assert_eq!(
+            "day 16, line 65, column 24: expected a flow rate of 0, as at most 64 valves can have \
+             one, found `1`",
+            err.to_string()
+        );
+        let err = parse(
+            "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+             Valve BB has flow rate=2; tunnel leads to valve AA\n\
+             Valve CC has flow rate=3; tunnel leads to valve BB",
+        )
+        .unwrap_err();
+        assert_eq!(
+            "day 16, line 3, column 24: expected a flow rate of 0, as `CC` cannot be reached from \
+             `AA`, found `3`",
+            err.to_string()
+        );
+        let err = parse("Valve AA has flow rate=x; tunnel leads to valve BB").unwrap_err();
+        assert_eq!(
+            "day 16, line 1, column 24: expected a number, found `x`",
+            err.to_string()
+        );
+    }
+}
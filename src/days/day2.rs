@@ -0,0 +1,184 @@
+use std::io::BufRead;
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, Lines, ReadError, ReadLines},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn score(self) -> u64 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    /// The shape this one defeats.
+    fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape that defeats this one.
+    fn loses_to(self) -> Shape {
+        self.beats().beats()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn score(self) -> u64 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// The second column of the strategy guide: the shape to play in part 1, how the round must end
+/// in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<(Shape, Column)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rounds: &Self::Input) -> Answer {
+        part1(rounds).into()
+    }
+
+    fn part2(rounds: &Self::Input) -> Answer {
+        part2(rounds).into()
+    }
+}
+
+/// The opponent's move and the second column of the strategy guide for each round.
+pub fn parse(input: &str) -> Result<Vec<(Shape, Column)>, ParseError> {
+    Lines::new(Day2::DAY, input)
+        .map(|mut line| parse_round(&mut line))
+        .collect()
+}
+
+fn parse_round(line: &mut Cursor) -> Result<(Shape, Column), ParseError> {
+    let opponent = match line.one_of(&["A", "B", "C"])? {
+        "A" => Shape::Rock,
+        "B" => Shape::Paper,
+        _ => Shape::Scissors,
+    };
+    line.literal(" ")?;
+    let column = match line.one_of(&["X", "Y", "Z"])? {
+        "X" => Column::X,
+        "Y" => Column::Y,
+        _ => Column::Z,
+    };
+    line.end()?;
+    Ok((opponent, column))
+}
+
+/// Both parts, scoring each round as it is read from `input`.
+pub fn stream(input: impl BufRead) -> Result<(u64, u64), ReadError> {
+    let mut lines = ReadLines::new(Day2::DAY, input);
+    let mut scores = (0, 0);
+    while let Some(mut line) = lines.next_line()? {
+        let (opponent, column) = parse_round(&mut line)?;
+        scores.0 += shape_score(opponent, column);
+        scores.1 += outcome_score(opponent, column);
+    }
+    Ok(scores)
+}
+
+pub fn part1(rounds: &[(Shape, Column)]) -> u64 {
+    rounds.iter().map(|&(a, b)| shape_score(a, b)).sum()
+}
+
+/// The score of a round where the column is the shape to play.
+fn shape_score(opponent: Shape, column: Column) -> u64 {
+    let response = match column {
+        Column::X => Shape::Rock,
+        Column::Y => Shape::Paper,
+        Column::Z => Shape::Scissors,
+    };
+    let outcome = if response == opponent {
+        Outcome::Draw
+    } else if response.beats() == opponent {
+        Outcome::Win
+    } else {
+        Outcome::Lose
+    };
+    response.score() + outcome.score()
+}
+
+pub fn part2(rounds: &[(Shape, Column)]) -> u64 {
+    rounds.iter().map(|&(a, b)| outcome_score(a, b)).sum()
+}
+
+/// The score of a round where the column is how it must end.
+fn outcome_score(opponent: Shape, column: Column) -> u64 {
+    let (outcome, response) = match column {
+        Column::X => (Outcome::Lose, opponent.beats()),
+        Column::Y => (Outcome::Draw, opponent),
+        Column::Z => (Outcome::Win, opponent.loses_to()),
+    };
+    response.score() + outcome.score()
+}
+
+/// A strategy guide of `size` rounds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let opponent = rng.choose(&["A", "B", "C"]);
+            let response = rng.choose(&["X", "Y", "Z"]);
+            format!("{opponent} {response}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn test() {
+        let input = r"A Y
+B X
+C Z";
+        let rounds = parse(input).unwrap();
+        assert_eq!(15, part1(&rounds));
+        assert_eq!(12, part2(&rounds));
+
+        // every pairing: 4+8+3 1+5+9 7+2+6 for shapes, 3+4+8 1+5+9 2+6+7 for outcomes
+        let every = parse("A X\nA Y\nA Z\nB X\nB Y\nB Z\nC X\nC Y\nC Z\n").unwrap();
+        assert_eq!(45, part1(&every));
+        assert_eq!(45, part2(&every));
+    }
+}
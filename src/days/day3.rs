@@ -0,0 +1,191 @@
+use std::io::BufRead;
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, Lines, ReadError, ReadLines},
+    Answer, ParseError, Solution,
+};
+
+static LETTERS: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L',
+    'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        part1(rucksacks).into()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        part2(rucksacks).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = Lines::new(Day3::DAY, input);
+    let mut rucksacks: Vec<String> = Vec::new();
+    for mut line in lines.by_ref() {
+        rucksacks.push(parse_rucksack(&mut line)?.to_owned());
+        if rucksacks.len().is_multiple_of(3) {
+            let group = rucksacks[rucksacks.len() - 3..]
+                .iter()
+                .map(|elf| items(elf));
+            parse_badge(group, &line)?;
+        }
+    }
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(lines.end_of_input("a rucksack to complete the group of three"));
+    }
+    Ok(rucksacks)
+}
+
+fn parse_rucksack<'a>(line: &mut Cursor<'a>) -> Result<&'a str, ParseError> {
+    let items = line.take_while(|c| c.is_ascii_alphabetic());
+    if items.is_empty() || !line.is_empty() {
+        return Err(line.error("an item letter"));
+    }
+    if !items.len().is_multiple_of(2) {
+        return Err(line.error("an even number of items"));
+    }
+    if in_both(items) == 0 {
+        return Err(line.error("an item in both compartments"));
+    }
+    Ok(items)
+}
+
+/// The badge of a group, whose last rucksack is `line`.
+fn parse_badge(group: impl IntoIterator<Item = u64>, line: &Cursor) -> Result<usize, ParseError> {
+    match common(group) {
+        0 => Err(line.error("an item the whole group carries")),
+        items => Ok(priority(items)),
+    }
+}
+
+/// Both parts, reading `input` as it goes and keeping only the items of the group so far.
+pub fn stream(input: impl BufRead) -> Result<(usize, usize), ReadError> {
+    let mut lines = ReadLines::new(Day3::DAY, input);
+    let mut priorities = (0, 0);
+    let mut group = Vec::with_capacity(3);
+    while let Some(mut line) = lines.next_line()? {
+        let rucksack = parse_rucksack(&mut line)?;
+        priorities.0 += misplaced(rucksack);
+        group.push(items(rucksack));
+        if group.len() == 3 {
+            priorities.1 += parse_badge(group.drain(..), &line)?;
+        }
+    }
+    if !group.is_empty() {
+        return Err(lines
+            .end_of_input("a rucksack to complete the group of three")
+            .into());
+    }
+    Ok(priorities)
+}
+
+/// The items in `rucksack`, as a bitmask where bit `p` is the item of priority `p`.
+fn items(rucksack: &str) -> u64 {
+    rucksack.bytes().fold(0, |items, c| {
+        let priority = match c {
+            b'a'..=b'z' => c - b'a' + 1,
+            _ => c - b'A' + 27,
+        };
+        items | 1 << priority
+    })
+}
+
+/// The priority of the one item in `items`.
+fn priority(items: u64) -> usize {
+    items.trailing_zeros() as usize
+}
+
+/// The items found in both compartments of `rucksack`.
+fn in_both(rucksack: &str) -> u64 {
+    let (first, second) = rucksack.split_at(rucksack.len() / 2);
+    items(first) & items(second)
+}
+
+/// The items found in every rucksack of `group`.
+fn common(group: impl IntoIterator<Item = u64>) -> u64 {
+    group
+        .into_iter()
+        .fold(u64::MAX, |common, items| common & items)
+}
+
+/// The priority of the item in both compartments.
+fn misplaced(rucksack: &str) -> usize {
+    priority(in_both(rucksack))
+}
+
+pub fn part1(rucksacks: &[String]) -> usize {
+    rucksacks.iter().map(|rucksack| misplaced(rucksack)).sum()
+}
+
+pub fn part2(rucksacks: &[String]) -> usize {
+    rucksacks
+        .chunks(3)
+        .map(|elfs| priority(common(elfs.iter().map(|elf| items(elf)))))
+        .sum()
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut rucksacks = Vec::new();
+    for _ in 0..size.max(1) {
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+        // the badge, the item shared by each rucksack's compartments, then one pool per compartment
+        let badge = letters[0];
+        let shared = &letters[1..4];
+        let pools: Vec<&[char]> = letters[4..].chunks(8).collect();
+
+        for (i, &shared) in shared.iter().enumerate() {
+            let len = rng.range(2..=16);
+            let mut compartments = [vec![shared], vec![shared]];
+            compartments[rng.below(2)].push(badge);
+            for (compartment, pool) in compartments.iter_mut().zip(&pools[2 * i..]) {
+                while compartment.len() < len {
+                    compartment.push(*rng.choose(pool));
+                }
+                rng.shuffle(compartment);
+            }
+            rucksacks.push(compartments.concat().into_iter().collect::<String>());
+        }
+    }
+    rucksacks.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn test() {
+        let input = r"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+        let rucksacks = parse(input).unwrap();
+        assert_eq!(157, part1(&rucksacks));
+        assert_eq!(70, part2(&rucksacks));
+
+        assert_eq!(
+            "day 3, line 1, column 5: expected an item in both compartments, found end of line",
+            parse("abcd\n").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "day 3, line 3, column 3: expected an item the whole group carries, found end of line",
+            parse("aa\nbb\ncc\n").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::io::BufRead;
+
+use crate::{
+    generate::Rng,
+    intervals::Intervals,
+    parse::{Cursor, Lines, ReadError, ReadLines},
+    Answer, ParseError, Solution,
+};
+
+/// The section ranges assigned to a pair of elves.
+pub type Pair = ((usize, usize), (usize, usize));
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        part1(pairs).into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        part2(pairs).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    Lines::new(Day4::DAY, input)
+        .map(|mut line| parse_pair(&mut line))
+        .collect()
+}
+
+fn parse_pair(line: &mut Cursor) -> Result<Pair, ParseError> {
+    let first = range(line)?;
+    line.literal(",")?;
+    let second = range(line)?;
+    line.end()?;
+
+    Ok((first, second))
+}
+
+/// One elf's `start-end` sections.
+fn range(line: &mut Cursor) -> Result<(usize, usize), ParseError> {
+    let at = *line;
+    let start = line.number()?;
+    line.literal("-")?;
+    let end = line.number()?;
+    if start > end {
+        return Err(at.error("a range whose start is not after its end"));
+    }
+    Ok((start, end))
+}
+
+/// Both parts, counting each pair as it is read from `input`.
+pub fn stream(input: impl BufRead) -> Result<(usize, usize), ReadError> {
+    let mut lines = ReadLines::new(Day4::DAY, input);
+    let mut counts = (0, 0);
+    while let Some(mut line) = lines.next_line()? {
+        let (a, b) = sections(&parse_pair(&mut line)?);
+        counts.0 += contains(&a, &b) as usize;
+        counts.1 += overlaps(&a, &b) as usize;
+    }
+    Ok(counts)
+}
+
+/// Both elves' sections, as sets.
+fn sections(&((x1, y1), (x2, y2)): &Pair) -> (Intervals<usize>, Intervals<usize>) {
+    (Intervals::from(x1..y1 + 1), Intervals::from(x2..y2 + 1))
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .map(sections)
+        .filter(|(a, b)| contains(a, b))
+        .count()
+}
+
+/// Whether one of the assignments covers the other.
+fn contains(a: &Intervals<usize>, b: &Intervals<usize>) -> bool {
+    let both = a.intersection(b);
+    &both == a || &both == b
+}
+
+fn overlaps(a: &Intervals<usize>, b: &Intervals<usize>) -> bool {
+    !a.intersection(b).is_empty()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .map(sections)
+        .filter(|(a, b)| overlaps(a, b))
+        .count()
+}
+
+/// `size` pairs of section assignments between 1 and 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut assignment = || {
+        let start = rng.range(1..=99);
+        (start, rng.range(start..=99))
+    };
+    (0..size)
+        .map(|_| {
+            let ((x1, y1), (x2, y2)) = (assignment(), assignment());
+            format!("{x1}-{y1},{x2}-{y2}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn test() {
+        let input = r"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+        let pairs = parse(input).unwrap();
+        assert_eq!(2, part1(&pairs));
+        assert_eq!(4, part2(&pairs));
+
+        assert_eq!(
+            "day 4, line 1, column 1: expected a range whose start is not after its end, found `5`",
+            parse("5-3,1-9").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "day 4, line 2, column 5: expected a range whose start is not after its end, found `9`",
+            parse("1-9,1-9\n1-9,9-1").unwrap_err().to_string()
+        );
+    }
+}
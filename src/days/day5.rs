@@ -0,0 +1,297 @@
+use crate::{
+    animate::Simulation,
+    generate::Rng,
+    parse::{Cursor, Lines},
+    point::Point,
+    Answer, ParseError, Solution,
+};
+
+/// The starting stacks, bottom crate first, and the `(count, from, to)` moves of the crane.
+#[derive(Debug, Clone)]
+pub struct Supplies {
+    pub crates: Vec<Vec<char>>,
+    pub instructions: Vec<(usize, usize, usize)>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Supplies;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(supplies: &Self::Input) -> Answer {
+        solution(supplies, false).into()
+    }
+
+    fn part2(supplies: &Self::Input) -> Answer {
+        solution(supplies, true).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Supplies, ParseError> {
+    let mut lines = Lines::new(Day5::DAY, input);
+
+    // the crates row by row, top first, until the line numbering the stacks
+    let mut rows = Vec::new();
+    let stacks = 'diagram: loop {
+        let mut line = lines.expect_line("a row of crates")?;
+        if line
+            .rest()
+            .trim_start()
+            .starts_with(|c: char| c.is_ascii_digit())
+        {
+            let mut stacks = 0;
+            loop {
+                line.literal(" ")?;
+                let label = line;
+                stacks += 1;
+                if line.number::<usize>()? != stacks {
+                    return Err(label.error(format!("stack number {stacks}")));
+                }
+                line.eat(" ");
+                if line.is_empty() {
+                    break 'diagram stacks;
+                }
+                line.literal(" ")?;
+            }
+        }
+
+        let mut row = Vec::new();
+        loop {
+            let cell = line;
+            if line.eat("   ") {
+                row.push((cell, None));
+            } else {
+                line.literal("[")?;
+                let name = line.take_while(|c| c.is_ascii_uppercase());
+                if name.len() != 1 {
+                    return Err(cell.error("a crate like `[A]` or an empty slot"));
+                }
+                line.literal("]")?;
+                row.push((cell, name.chars().next()));
+            }
+            if line.is_empty() {
+                break;
+            }
+            line.literal(" ")?;
+        }
+        rows.push(row);
+    };
+
+    let mut crates = vec![Vec::new(); stacks];
+    for row in rows.iter().rev() {
+        if let Some((cell, _)) = row.get(stacks) {
+            return Err(cell.error("end of line"));
+        }
+        for (stack, &(_, name)) in row.iter().enumerate() {
+            crates[stack].extend(name);
+        }
+    }
+
+    lines.expect_line("an empty line")?.end()?;
+
+    let stack = |line: &mut Cursor| {
+        let label = *line;
+        match line.number::<usize>()? {
+            n @ 1.. if n <= stacks => Ok(n - 1),
+            _ => Err(label.error(format!("a stack between 1 and {stacks}"))),
+        }
+    };
+    // how many crates each stack holds, so no move takes more than there are
+    let mut heights: Vec<usize> = crates.iter().map(Vec::len).collect();
+    let instructions = lines
+        .map(|mut line| {
+            line.literal("move ")?;
+            let count = line;
+            let num_crates = line.number()?;
+            line.literal(" from ")?;
+            let src = stack(&mut line)?;
+            line.literal(" to ")?;
+            let dest = stack(&mut line)?;
+            line.end()?;
+            if num_crates > heights[src] {
+                return Err(count.error(format!(
+                    "at most {} crates, as stack {} holds",
+                    heights[src],
+                    src + 1
+                )));
+            }
+            heights[src] -= num_crates;
+            heights[dest] += num_crates;
+            Ok((num_crates, src, dest))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Supplies {
+        crates,
+        instructions,
+    })
+}
+
+/// Moves `count` crates from `src` to `dest`, one at a time, or all at once with the CrateMover
+/// 9001 of part 2.
+fn apply(crates: &mut [Vec<char>], (count, src, dest): (usize, usize, usize), part2: bool) {
+    let keep = crates[src].len() - count;
+    let mut moved = crates[src].split_off(keep);
+    if !part2 {
+        moved.reverse();
+    }
+    crates[dest].append(&mut moved);
+}
+
+pub fn solution(supplies: &Supplies, part2: bool) -> String {
+    let mut crates = supplies.crates.clone();
+    for &instruction in &supplies.instructions {
+        apply(&mut crates, instruction, part2);
+    }
+    crates.iter().filter_map(|row| row.last()).collect()
+}
+
+/// The crane carrying out one move per step, drawn like the puzzle's diagram with the stack
+/// numbers on row 0 and the crates above them.
+pub struct Animation {
+    crates: Vec<Vec<char>>,
+    instructions: Vec<(usize, usize, usize)>,
+    part2: bool,
+    done: usize,
+}
+
+impl Animation {
+    pub fn new(supplies: &Supplies, part2: bool) -> Self {
+        Animation {
+            crates: supplies.crates.clone(),
+            instructions: supplies.instructions.clone(),
+            part2,
+            done: 0,
+        }
+    }
+}
+
+impl Simulation for Animation {
+    fn step(&mut self) -> bool {
+        let Some(&instruction) = self.instructions.get(self.done) else {
+            return false;
+        };
+        apply(&mut self.crates, instruction, self.part2);
+        self.done += 1;
+        true
+    }
+
+    fn cell(&self, pos: Point) -> char {
+        let (stack, column) = (pos.x / 4, pos.x % 4);
+        if pos.x < 0 || stack as usize >= self.crates.len() || pos.y > 0 || column == 3 {
+            return ' ';
+        }
+        if pos.y == 0 {
+            return match column {
+                1 => char::from_digit(stack as u32 + 1, 10).unwrap_or('?'),
+                _ => ' ',
+            };
+        }
+        match self.crates[stack as usize].get((-pos.y - 1) as usize) {
+            Some(&name) => ['[', name, ']'][column as usize],
+            None => ' ',
+        }
+    }
+
+    /// The top of the stack the last move put crates on.
+    fn focus(&self) -> Point {
+        let stack = match self.done {
+            0 => 0,
+            n => self.instructions[n - 1].2,
+        };
+        Point::new(stack as isize * 4 + 1, -(self.crates[stack].len() as isize))
+    }
+
+    fn status(&self) -> String {
+        match self.done {
+            0 => format!("move 0 of {}", self.instructions.len()),
+            n => {
+                let (count, src, dest) = self.instructions[n - 1];
+                format!(
+                    "move {n} of {}: {count} from {} to {}",
+                    self.instructions.len(),
+                    src + 1,
+                    dest + 1
+                )
+            }
+        }
+    }
+}
+
+/// Up to nine stacks and `size` moves, each taking crates from a stack that has them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let stacks = rng.range(2..=9);
+    let mut crates: Vec<Vec<char>> = vec![Vec::new(); stacks];
+    for stack in crates.iter_mut() {
+        for _ in 0..rng.range(0..=8) {
+            stack.push(rng.range(b'A'..=b'Z') as char);
+        }
+    }
+    if crates.iter().all(Vec::is_empty) {
+        crates[0].push('A');
+    }
+
+    let height = crates.iter().map(Vec::len).max().unwrap();
+    let mut input = String::new();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = crates
+            .iter()
+            .map(|stack| {
+                stack
+                    .get(row)
+                    .map_or("   ".to_owned(), |c| format!("[{c}]"))
+            })
+            .collect();
+        input += &(cells.join(" ") + "\n");
+    }
+    let labels: Vec<String> = (1..=stacks).map(|n| format!(" {n} ")).collect();
+    input += &(labels.join(" ") + "\n\n");
+
+    for _ in 0..size {
+        let sources: Vec<usize> = (0..stacks).filter(|&s| !crates[s].is_empty()).collect();
+        let src = *rng.choose(&sources);
+        let dest = (src + rng.range(1..=stacks - 1)) % stacks;
+        let count = rng.range(1..=crates[src].len());
+        let keep = crates[src].len() - count;
+        let moved = crates[src].split_off(keep);
+        crates[dest].extend(moved);
+        input += &format!("move {count} from {} to {}\n", src + 1, dest + 1);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, solution};
+
+    #[test]
+    fn test() {
+        let input = concat!(
+            "    [D]    \n",
+            "[N] [C]    \n",
+            "[Z] [M] [P]\n",
+            " 1   2   3 \n",
+            "\n",
+            "move 1 from 2 to 1\n",
+            "move 3 from 1 to 3\n",
+            "move 2 from 2 to 1\n",
+            "move 1 from 1 to 2\n",
+        );
+        let supplies = parse(input).unwrap();
+        assert_eq!("CMZ", solution(&supplies, false));
+        assert_eq!("MCD", solution(&supplies, true));
+
+        let err = parse("[A]\n 1 \n\nmove 3 from 1 to 1").unwrap_err();
+        assert_eq!(
+            "day 5, line 4, column 6: expected at most 1 crates, as stack 1 holds, found `3`",
+            err.to_string()
+        );
+        // the crates moved there earlier count
+        assert!(parse("[A]    \n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 2 to 1").is_ok());
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Bytes},
+    iter::Peekable,
+};
+
+use crate::{
+    generate::Rng,
+    parse::{Lines, ReadError},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = Lines::new(Self::DAY, input);
+        let mut line = lines.expect_line("the datastream")?;
+        let signal = line.take_while(|c| c.is_ascii_lowercase());
+        if !line.is_empty() {
+            return Err(line.error("a lowercase letter"));
+        }
+        if let Some(line) = lines.next() {
+            return Err(line.error("end of input"));
+        }
+        Ok(signal.chars().collect())
+    }
+
+    fn part1(signal: &Self::Input) -> Answer {
+        solution(signal, 4).into()
+    }
+
+    fn part2(signal: &Self::Input) -> Answer {
+        solution(signal, 14).into()
+    }
+}
+
+pub fn solution(signal: &[char], size: usize) -> usize {
+    signal
+        .windows(size)
+        .take_while(|chars| chars.iter().collect::<HashSet<_>>().len() != size)
+        .count()
+        + size
+}
+
+/// Both parts, reading the datastream from `input` a byte at a time and keeping only the last
+/// fourteen, as the whole stream is a single line.
+pub fn stream(input: impl BufRead) -> Result<(usize, usize), ReadError> {
+    const SIZES: [usize; 2] = [4, 14];
+    let mut bytes = input.bytes().peekable();
+    let mut recent = [0; 14];
+    let mut read = 0;
+    let mut markers = [None; 2];
+
+    if bytes.peek().is_none() {
+        return Err(error(1, 1, "the datastream", "end of input".to_owned()).into());
+    }
+    while let Some(c) = bytes.next().transpose()? {
+        match c {
+            b'a'..=b'z' => {
+                recent[read % recent.len()] = c;
+                read += 1;
+                for (marker, size) in markers.iter_mut().zip(SIZES) {
+                    if marker.is_none() && read >= size && distinct(&recent, read, size) {
+                        *marker = Some(read);
+                    }
+                }
+            }
+            _ if line_end(c, &mut bytes) => {
+                if let Some(c) = bytes.next().transpose()? {
+                    let found = if line_end(c, &mut bytes) {
+                        "end of line".to_owned()
+                    } else {
+                        found(c, &mut bytes)?
+                    };
+                    return Err(error(2, 1, "end of input", found).into());
+                }
+            }
+            _ => {
+                let found = found(c, &mut bytes)?;
+                return Err(error(1, read + 1, "a lowercase letter", found).into());
+            }
+        }
+    }
+
+    let [part1, part2] = [0, 1].map(|i| markers[i].unwrap_or((read + 1).max(SIZES[i])));
+    Ok((part1, part2))
+}
+
+/// Whether the last `size` of the `read` bytes kept in `recent` all differ.
+fn distinct(recent: &[u8], read: usize, size: usize) -> bool {
+    let mut seen = 0u32;
+    for i in read - size..read {
+        let bit = 1 << (recent[i % recent.len()] - b'a');
+        if seen & bit != 0 {
+            return false;
+        }
+        seen |= bit;
+    }
+    true
+}
+
+/// Whether `c` ends a line, taking the `\n` after a `\r` too.
+fn line_end(c: u8, bytes: &mut Peekable<Bytes<impl BufRead>>) -> bool {
+    match c {
+        b'\n' => true,
+        b'\r' if matches!(bytes.peek(), Some(Ok(b'\n'))) => {
+            bytes.next();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// The character starting with byte `first`, quoted as [`ParseError`]s show it.
+fn found(first: u8, bytes: &mut Peekable<Bytes<impl BufRead>>) -> io::Result<String> {
+    let mut char = vec![first];
+    while char.len() < 4 && matches!(bytes.peek(), Some(Ok(0x80..=0xbf))) {
+        char.push(bytes.next().transpose()?.unwrap());
+    }
+    let c = String::from_utf8_lossy(&char).chars().next().unwrap();
+    Ok(format!("`{c}`"))
+}
+
+fn error(line: usize, column: usize, expected: &str, found: String) -> ParseError {
+    ParseError {
+        day: Day6::DAY,
+        line,
+        column,
+        expected: expected.to_owned(),
+        found,
+    }
+}
+
+/// `size` letters from a random alphabet, followed by fourteen different letters so that both
+/// markers exist.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let alphabet = rng.range(b'c'..=b'z');
+    let mut signal: String = (0..size)
+        .map(|_| rng.range(b'a'..=alphabet) as char)
+        .collect();
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut marker);
+    signal.extend(&marker[..14]);
+    signal + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solution, Day6};
+    use crate::Solution;
+
+    #[test]
+    fn test() {
+        let streams = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (input, packet, message) in streams {
+            let signal = Day6::parse(input).unwrap();
+            assert_eq!(packet, solution(&signal, 4), "{input}");
+            assert_eq!(message, solution(&signal, 14), "{input}");
+        }
+    }
+}
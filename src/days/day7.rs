@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, Lines},
+    repl::Session,
+    trace::{self, trace},
+    Answer, ParseError, Solution,
+};
+
+static DISK_SIZE: usize = 70000000;
+static SPACE_REQUIRED: usize = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = HashMap<String, usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_fs(input)
+    }
+
+    fn part1(fs: &Self::Input) -> Answer {
+        part1(fs).into()
+    }
+
+    fn part2(fs: &Self::Input) -> Answer {
+        part2(fs).into()
+    }
+}
+
+/// Total size of every directory, keyed by its path without the leading `/`.
+pub fn parse_fs(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+    let lines: Vec<_> = Lines::new(Day7::DAY, input).collect();
+
+    let mut cwd = Vec::new();
+    let mut fs: HashMap<String, usize> = HashMap::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let mut line = lines[i];
+        line.literal("$ ")?;
+        match line.one_of(&["cd ", "ls"])? {
+            "cd " => {
+                let dir = line.rest();
+                if dir.is_empty() {
+                    return Err(line.error("a directory"));
+                }
+                match dir {
+                    ".." => {
+                        cwd.pop();
+                    }
+                    "/" => {
+                        cwd.clear();
+                    }
+                    _ => cwd.push(dir),
+                }
+            }
+            _ => {
+                line.end()?;
+                let path = cwd.join("/");
+                let mut sum = 0;
+                i += 1;
+
+                while i < lines.len() && !lines[i].text().starts_with("$") {
+                    let mut line = lines[i];
+                    if !line.eat("dir ") {
+                        let size = line.number::<usize>()?;
+                        line.literal(" ")?;
+
+                        sum += size;
+                    }
+                    if line.is_empty() {
+                        return Err(line.error("a name"));
+                    }
+                    i += 1;
+                }
+                i -= 1;
+                fs.insert(path.clone(), sum);
+
+                for i in 0..cwd.len().saturating_sub(1) {
+                    let dir = &cwd[0..cwd.len().saturating_sub(1 + i)].join("/");
+
+                    fs.entry(dir.to_string()).and_modify(|size| {
+                        *size += sum;
+                    });
+                }
+                if !path.is_empty() {
+                    fs.entry("".to_owned()).and_modify(|size| {
+                        *size += sum;
+                    });
+                }
+            }
+        }
+        i += 1;
+    }
+
+    if !fs.contains_key("") {
+        // the whole session has been read, so the listing is missing at its end
+        let mut lines = Lines::new(Day7::DAY, input);
+        lines.by_ref().for_each(drop);
+        return Err(lines.end_of_input("`$ ls` in `/`"));
+    }
+
+    if trace::enabled() {
+        let mut dirs: Vec<_> = fs.iter().collect();
+        dirs.sort();
+        for (path, size) in dirs {
+            trace!(
+                Day7::DAY,
+                "directory",
+                path = format!("/{path}"),
+                size = size
+            );
+        }
+    }
+    Ok(fs)
+}
+
+pub fn part1(fs: &HashMap<String, usize>) -> usize {
+    fs.values().filter(|&&x| x <= 100000).sum()
+}
+
+pub fn part2(fs: &HashMap<String, usize>) -> usize {
+    let used = fs[""];
+    let free_space = DISK_SIZE.saturating_sub(used);
+    let space_to_free = SPACE_REQUIRED.saturating_sub(free_space);
+
+    let mut values: Vec<(&String, &usize)> = fs.iter().collect();
+
+    values.sort_by_key(|(_, a)| *a);
+
+    for (_, size) in values {
+        if *size > space_to_free {
+            return *size;
+        }
+    }
+    // every directory is empty, so deleting the root frees as much as anything
+    used
+}
+
+/// The sizes of the directories, by path.
+pub struct Explorer {
+    fs: HashMap<String, usize>,
+}
+
+impl Explorer {
+    pub fn new(fs: HashMap<String, usize>) -> Self {
+        Explorer { fs }
+    }
+}
+
+impl Session for Explorer {
+    fn help(&self) -> &'static [&'static str] {
+        &["size <path>: the total size of a directory, like `size /a/e`"]
+    }
+
+    fn query(&self, line: &mut Cursor) -> Result<String, ParseError> {
+        line.literal("size ")?;
+        let start = *line;
+        line.literal("/")?;
+        let path = line.rest().trim_end_matches('/');
+        match self.fs.get(path) {
+            Some(size) => Ok(size.to_string()),
+            None => Err(start.error("a directory of the terminal output")),
+        }
+    }
+}
+
+/// A terminal session exploring `size` directories, with enough in them that part 2 has to free
+/// some space.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let dirs = size.max(1);
+    let mut children = vec![Vec::new(); dirs];
+    for dir in 1..dirs {
+        children[rng.below(dir)].push(dir);
+    }
+
+    let mut files: Vec<Vec<usize>> = (0..dirs)
+        .map(|_| (0..rng.below(5)).map(|_| rng.range(1..=300000)).collect())
+        .collect();
+    files[rng.below(dirs)].push(rng.range(1..=300000));
+
+    // scaled to fill the disk past what part 2 needs, the last file taking up the slack
+    let used = rng.range(DISK_SIZE - SPACE_REQUIRED + 1..=DISK_SIZE);
+    let total: usize = files.iter().flatten().sum();
+    for size in files.iter_mut().flatten() {
+        *size = (*size * used / total).max(1);
+    }
+    let total: usize = files.iter().flatten().sum();
+    let last = files.iter_mut().flatten().last().unwrap();
+    *last = *last + used - total;
+
+    fn explore(
+        dir: usize,
+        children: &[Vec<usize>],
+        files: &[Vec<usize>],
+        rng: &mut Rng,
+    ) -> Vec<String> {
+        let mut listing: Vec<String> = children[dir].iter().map(|c| format!("dir d{c}")).collect();
+        listing.extend(
+            files[dir]
+                .iter()
+                .enumerate()
+                .map(|(i, size)| format!("{size} f{i}.txt")),
+        );
+        rng.shuffle(&mut listing);
+
+        let mut session = vec!["$ ls".to_owned()];
+        session.extend(listing);
+        for &child in &children[dir] {
+            session.push(format!("$ cd d{child}"));
+            session.extend(explore(child, children, files, rng));
+            session.push("$ cd ..".to_owned());
+        }
+        session
+    }
+
+    let mut session = vec!["$ cd /".to_owned()];
+    session.extend(explore(0, &children, &files, rng));
+    session.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_fs, part1, part2, Explorer};
+    use crate::{parse::Cursor, repl::Session};
+
+    #[test]
+    fn test() {
+        let input = r"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+        let fs = parse_fs(input).unwrap();
+        assert_eq!(Some(&584), fs.get("a/e"));
+        assert_eq!(Some(&48381165), fs.get(""));
+        assert_eq!(95437, part1(&fs));
+        assert_eq!(24933642, part2(&fs));
+
+        assert_eq!(
+            "day 7, line 1, column 1: expected `$ ls` in `/`, found end of input",
+            parse_fs("").unwrap_err().to_string()
+        );
+        assert!(parse_fs("$ cd a\n$ ls\n12 b").is_err());
+        // plenty of free space already: the smallest directory will do
+        let roomy = parse_fs("$ cd /\n$ ls\ndir a\n5 b\n$ cd a\n$ ls\n7 c").unwrap();
+        assert_eq!(7, part2(&roomy));
+        // nothing to delete
+        assert_eq!(0, part2(&parse_fs("$ cd /\n$ ls").unwrap()));
+
+        let explorer = Explorer::new(fs);
+        let size = |command| {
+            explorer
+                .query(&mut Cursor::new(7, 1, command))
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(Ok("584".to_owned()), size("size /a/e"));
+        assert_eq!(Ok("94853".to_owned()), size("size /a/"));
+        assert_eq!(Ok("48381165".to_owned()), size("size /"));
+        assert_eq!(
+            Err(
+                "day 7, line 1, column 6: expected a directory of the terminal output, found `/`"
+                    .to_owned()
+            ),
+            size("size /a/x")
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{generate::Rng, grid::Grid, image::Rgb, point::Point, Answer, ParseError, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(trees: &Self::Input) -> Answer {
+        part1(trees).into()
+    }
+
+    fn part2(trees: &Self::Input) -> Answer {
+        part2(trees).into()
+    }
+}
+
+/// Tree heights.
+pub fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(Day8::DAY, input, |_, c| {
+        c.to_digit(10)
+            .map(|height| height as usize)
+            .ok_or_else(|| "a tree height".to_owned())
+    })
+}
+
+/// Whether the tree at `pos` can be seen from outside the forest, over all the trees on one side.
+fn visible(trees: &Grid<usize>, pos: Point) -> bool {
+    let (x, y, tree) = (pos.x as usize, pos.y as usize, trees[pos]);
+    let (row, column) = (trees.row(y), || trees.column(x));
+    row[..x].iter().all(|&t| t < tree)
+        || row[x + 1..].iter().all(|&t| t < tree)
+        || column().take(y).all(|&t| t < tree)
+        || column().skip(y + 1).all(|&t| t < tree)
+}
+
+pub fn part1(trees: &Grid<usize>) -> usize {
+    trees.positions().filter(|&pos| visible(trees, pos)).count()
+}
+
+fn get_trees(tree: usize, iter: impl Iterator<Item = usize>) -> usize {
+    let mut score = 0;
+    for other in iter {
+        if other < tree {
+            score += 1;
+        } else {
+            score += 1;
+            break;
+        }
+    }
+    score
+}
+
+pub fn part2(trees: &Grid<usize>) -> usize {
+    let mut max_score = 0;
+
+    for (pos, &tree) in trees.iter() {
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        let row = trees.row(y);
+        let left = get_trees(tree, row[..x].iter().rev().cloned());
+        let right = get_trees(tree, row[x + 1..].iter().cloned());
+        let up = get_trees(tree, trees.column(x).take(y).rev().cloned());
+        let down = get_trees(tree, trees.column(x).skip(y + 1).cloned());
+
+        let score = left * right * up * down;
+
+        if score > max_score {
+            max_score = score;
+        }
+    }
+
+    max_score
+}
+
+/// The forest a pixel per tree, taller trees lighter, and the visible ones green.
+pub fn image(trees: &Grid<usize>) -> Grid<Rgb> {
+    let mut image = trees.map(|&height| Rgb(40, 40, 40).mix(Rgb(140, 140, 140), height, 9));
+    for pos in trees.positions() {
+        if visible(trees, pos) {
+            image[pos] = Rgb(0, 90, 0).mix(Rgb(120, 255, 120), trees[pos], 9);
+        }
+    }
+    image
+}
+
+/// A square forest `size` trees wide, at least two.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size).map(|_| rng.range(b'0'..=b'9') as char).collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, part1, part2};
+
+    #[test]
+    fn test() {
+        let input = r"30373
+25512
+65332
+33549
+35390";
+        let trees = parse(input).unwrap();
+        assert_eq!(21, part1(&trees));
+        assert_eq!(8, part2(&trees));
+    }
+}
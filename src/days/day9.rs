@@ -0,0 +1,191 @@
+use std::{collections::HashSet, io::BufRead};
+
+use crate::{
+    animate::Simulation,
+    generate::Rng,
+    parse::{Cursor, Lines, ReadError, ReadLines},
+    point::{Direction, Point},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<(Direction, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(moves: &Self::Input) -> Answer {
+        simulate_rope(moves, 2).into()
+    }
+
+    fn part2(moves: &Self::Input) -> Answer {
+        simulate_rope(moves, 10).into()
+    }
+}
+
+/// The moves of the head, as a direction and a number of steps.
+pub fn parse(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+    Lines::new(Day9::DAY, input)
+        .map(|mut mov| parse_move(&mut mov))
+        .collect()
+}
+
+fn parse_move(mov: &mut Cursor) -> Result<(Direction, usize), ParseError> {
+    let direction = match mov.one_of(&["R ", "L ", "U ", "D "])? {
+        "R " => Direction::Right,
+        "L " => Direction::Left,
+        "U " => Direction::Up,
+        _ => Direction::Down,
+    };
+    let units = mov.number()?;
+    mov.end()?;
+    Ok((direction, units))
+}
+
+/// Both parts, pulling both ropes by each move as it is read from `input`. Only the positions
+/// their tails visited are kept.
+pub fn stream(input: impl BufRead) -> Result<(usize, usize), ReadError> {
+    let mut lines = ReadLines::new(Day9::DAY, input);
+    let mut ropes = [Rope::new(2), Rope::new(10)];
+    while let Some(mut mov) = lines.next_line()? {
+        let (direction, units) = parse_move(&mut mov)?;
+        for rope in &mut ropes {
+            for _ in 0..units {
+                rope.pull(direction);
+            }
+        }
+    }
+    let [short, long] = ropes.map(|rope| rope.visited.len());
+    Ok((short, long))
+}
+
+/// The knots of a rope, head first, and every position its tail has been.
+pub struct Rope {
+    pub knots: Vec<Point>,
+    pub visited: HashSet<Point>,
+}
+
+impl Rope {
+    pub fn new(length: usize) -> Self {
+        Rope {
+            knots: vec![Point::ORIGIN; length],
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Moves the head one step and lets the other knots follow.
+    pub fn pull(&mut self, direction: Direction) {
+        let rope = &mut self.knots;
+        rope[0] += direction.offset();
+        for i in 1..rope.len() {
+            // a knot only moves once it is no longer touching the one ahead of it
+            if rope[i].chebyshev(rope[i - 1]) <= 1 {
+                break;
+            }
+            rope[i] = rope[i].step_towards(rope[i - 1]);
+        }
+        self.visited.insert(*rope.last().unwrap());
+    }
+}
+
+pub fn simulate_rope(moves: &[(Direction, usize)], length: usize) -> usize {
+    let mut rope = Rope::new(length);
+    for &(direction, units) in moves {
+        for _ in 0..units {
+            rope.pull(direction);
+        }
+    }
+    rope.visited.len()
+}
+
+/// The rope moving one step of the head at a time, drawn like the puzzle's pictures: `H` for the
+/// head, then the knots numbered from 1, or `T` for the tail of a rope of two, over `#` where the
+/// tail has been.
+pub struct Animation {
+    rope: Rope,
+    steps: Vec<Direction>,
+    done: usize,
+}
+
+impl Animation {
+    pub fn new(moves: &[(Direction, usize)], length: usize) -> Self {
+        Animation {
+            rope: Rope::new(length),
+            steps: moves
+                .iter()
+                .flat_map(|&(direction, units)| std::iter::repeat_n(direction, units))
+                .collect(),
+            done: 0,
+        }
+    }
+}
+
+impl Simulation for Animation {
+    fn step(&mut self) -> bool {
+        let Some(&direction) = self.steps.get(self.done) else {
+            return false;
+        };
+        self.rope.pull(direction);
+        self.done += 1;
+        true
+    }
+
+    fn cell(&self, pos: Point) -> char {
+        let knots = &self.rope.knots;
+        match knots.iter().position(|&knot| knot == pos) {
+            Some(0) => 'H',
+            Some(1) if knots.len() == 2 => 'T',
+            Some(i) => char::from_digit(i as u32, 36).unwrap_or('?'),
+            None if pos == Point::ORIGIN => 's',
+            None if self.rope.visited.contains(&pos) => '#',
+            None => '.',
+        }
+    }
+
+    fn focus(&self) -> Point {
+        self.rope.knots[0]
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "step {} of {}, the tail visited {} positions",
+            self.done,
+            self.steps.len(),
+            self.rope.visited.len()
+        )
+    }
+}
+
+/// `size` moves of the head, up to twenty steps each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = rng.choose(&["R", "L", "U", "D"]);
+            format!("{direction} {}\n", rng.range(1..=20))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, simulate_rope};
+
+    #[test]
+    fn test_day9() {
+        let input = r"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+        let moves = parse(input).unwrap();
+        assert_eq!(13, simulate_rope(&moves, 2));
+        assert_eq!(1, simulate_rope(&moves, 10));
+    }
+}
@@ -0,0 +1,132 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use std::any::Any;
+
+use crate::{generate::Rng, Answer, ParseError, Solution};
+
+/// A registered day with its [`Solution`] erased, so every day can be driven the same way.
+pub struct Day {
+    pub day: u8,
+    /// The committed puzzle input, used when no other input is given.
+    pub input: &'static str,
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    part1: fn(&Parsed) -> Answer,
+    part2: fn(&Parsed) -> Answer,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+/// A day's parsed input, only usable with the [`Day`] that produced it.
+pub struct Parsed(Box<dyn Any>);
+
+impl Day {
+    pub const fn new<S: Solution>(
+        input: &'static str,
+        generate: fn(&mut Rng, usize) -> String,
+    ) -> Self
+    where
+        S::Input: 'static,
+    {
+        Day {
+            day: S::DAY,
+            input,
+            parse: |input| S::parse(input).map(|input| Parsed(Box::new(input))),
+            part1: |parsed| S::part1(parsed.0.downcast_ref().unwrap()),
+            part2: |parsed| S::part2(parsed.0.downcast_ref().unwrap()),
+            generate,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, parsed: &Parsed, part: u8) -> Answer {
+        match part {
+            1 => (self.part1)(parsed),
+            2 => (self.part2)(parsed),
+            _ => panic!("day {} has no part {part}", self.day),
+        }
+    }
+
+    /// A random, well-formed input; what `size` counts is up to each day's generator.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(include_str!("day1-input.txt"), day1::generate),
+    Day::new::<day2::Day2>(include_str!("day2-input.txt"), day2::generate),
+    Day::new::<day3::Day3>(include_str!("day3-input.txt"), day3::generate),
+    Day::new::<day4::Day4>(include_str!("day4-input.txt"), day4::generate),
+    Day::new::<day5::Day5>(include_str!("day5-input.txt"), day5::generate),
+    Day::new::<day6::Day6>(include_str!("day6-input.txt"), day6::generate),
+    Day::new::<day7::Day7>(include_str!("day7-input.txt"), day7::generate),
+    Day::new::<day8::Day8>(include_str!("day8-input.txt"), day8::generate),
+    Day::new::<day9::Day9>(include_str!("day9-input.txt"), day9::generate),
+    Day::new::<day10::Day10>(include_str!("day10-input.txt"), day10::generate),
+    Day::new::<day11::Day11>(include_str!("day11-input.txt"), day11::generate),
+    Day::new::<day12::Day12>(include_str!("day12-input.txt"), day12::generate),
+    Day::new::<day13::Day13>(include_str!("day13-input.txt"), day13::generate),
+    Day::new::<day14::Day14>(include_str!("day14-input.txt"), day14::generate),
+    Day::new::<day15::Day15>(include_str!("day15-input.txt"), day15::generate),
+    Day::new::<day16::Day16>(include_str!("day16-input.txt"), day16::generate),
+];
+
+/// The parts whose answer is an [`Answer::Text`], as `(day, part)`: the crate tops of day 5 and
+/// the screen of day 10. Every other answer is a number.
+pub const TEXT_PARTS: &[(u8, u8)] = &[(5, 1), (5, 2), (10, 2)];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get, DAYS, TEXT_PARTS};
+    use crate::Answer;
+
+    #[test]
+    fn test() {
+        // in order from day 1, as `aoc new` registers them
+        assert_eq!(Some(1), DAYS.first().map(|d| d.day));
+        assert!(DAYS.windows(2).all(|days| days[0].day < days[1].day));
+
+        let day5 = get(5).unwrap();
+        let parsed = day5.parse(day5.input).unwrap();
+        assert_eq!(Answer::Text("LBLVVTVLP".to_owned()), day5.solve(&parsed, 1));
+        assert_eq!(Answer::Text("TPFFBDRJD".to_owned()), day5.solve(&parsed, 2));
+
+        for day in DAYS {
+            let parsed = day.parse(&day.generate(0, 10)).unwrap();
+            // day 15 part 2 searches four million rows whatever the input
+            for part in [1, 2]
+                .into_iter()
+                .filter(|&part| (day.day, part) != (15, 2))
+            {
+                let text = matches!(day.solve(&parsed, part), Answer::Text(_));
+                assert_eq!(
+                    TEXT_PARTS.contains(&(day.day, part)),
+                    text,
+                    "day {}",
+                    day.day
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::http::Http;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2022;
+const USER_AGENT: &str = "aoc-2022-runner (std-only input fetcher)";
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    NotReleased(u8),
+    BadSession,
+    /// Too many requests, with how long the server asked us to wait if it said.
+    RateLimited(Option<String>),
+    Status(u16),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session cookie, set AOC_SESSION or save it in ~/.config/aoc/session"
+            ),
+            FetchError::NotReleased(day) => write!(f, "day {day} is not released yet"),
+            FetchError::BadSession => write!(f, "the server rejected the session cookie"),
+            FetchError::RateLimited(Some(after)) => {
+                write!(f, "rate limited, try again in {after} seconds")
+            }
+            FetchError::RateLimited(None) => write!(f, "rate limited, try again later"),
+            FetchError::Status(status) => write!(f, "unexpected HTTP status {status}"),
+            FetchError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+/// Where fetched inputs are kept: `$AOC_DATA_DIR`, or `aoc` in the user's data directory.
+pub fn data_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_DATA_DIR") {
+        return Some(dir.into());
+    }
+    let base = env::var_os("XDG_DATA_HOME")
+        .or_else(|| env::var_os("LOCALAPPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))?;
+    Some(base.join("aoc"))
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .or_else(|| env::var_os("APPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("aoc"))
+}
+
+pub fn cache_path(data_dir: &Path, day: u8) -> PathBuf {
+    data_dir.join(format!("inputs/{YEAR}/day{day}.txt"))
+}
+
+/// The session cookie: `$AOC_SESSION`, or the contents of `aoc/session` in the user's config
+/// directory.
+pub fn session() -> Option<String> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(config_dir()?.join("session")).ok()?,
+    };
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_owned())
+}
+
+/// Downloads a day's input from the server at `base_url`.
+pub fn fetch(
+    http: &dyn Http,
+    base_url: &str,
+    session: &str,
+    day: u8,
+) -> Result<String, FetchError> {
+    let url = format!("{}/{YEAR}/day/{day}/input", base_url.trim_end_matches('/'));
+    let cookie = format!("session={session}");
+    let response = http
+        .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])
+        .map_err(FetchError::Io)?;
+
+    match response.status {
+        200 => Ok(response.body),
+        404 => Err(FetchError::NotReleased(day)),
+        400 | 401 | 403 => Err(FetchError::BadSession),
+        429 => Err(FetchError::RateLimited(
+            response.header("Retry-After").map(str::to_owned),
+        )),
+        status => Err(FetchError::Status(status)),
+    }
+}
+
+pub fn save(path: &Path, input: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{cache_path, fetch, save, FetchError};
+    use crate::http::{backend, Http};
+
+    /// Serves canned responses the way the puzzle server answers them.
+    fn stand_in_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8(request).unwrap();
+
+                let response = if !request.contains("Cookie: session=good\r\n") {
+                    "HTTP/1.1 400 Bad Request\r\n\r\nPuzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                } else if request.starts_with("GET /2022/day/1/input ") {
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\n1000\n2000\n\n3000\n"
+                } else if request.starts_with("GET /2022/day/2/input ") {
+                    "HTTP/1.1 429 Too Many Requests\r\nretry-after: 60\r\n\r\n"
+                } else {
+                    "HTTP/1.1 404 Not Found\r\n\r\nPlease don't repeatedly request this endpoint before it unlocks!\n"
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn test() {
+        let url = stand_in_server();
+        let http: Box<dyn Http> = backend(&url);
+
+        let input = fetch(&*http, &url, "good", 1).unwrap();
+        assert_eq!("1000\n2000\n\n3000\n", input);
+        assert!(matches!(
+            fetch(&*http, &url, "good", 2),
+            Err(FetchError::RateLimited(Some(after))) if after == "60"
+        ));
+        assert!(matches!(
+            fetch(&*http, &url, "good", 25),
+            Err(FetchError::NotReleased(25))
+        ));
+        assert!(matches!(
+            fetch(&*http, &format!("{url}/"), "expired", 1),
+            Err(FetchError::BadSession)
+        ));
+
+        let data_dir = env::temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+        let path = cache_path(&data_dir, 1);
+        save(&path, &input).unwrap();
+        assert_eq!(input, fs::read_to_string(&path).unwrap());
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+}
@@ -0,0 +1,265 @@
+//! A C interface to the solvers, for tools that cannot call Rust. The library is built as a
+//! shared library (`libaoc.so`, `libaoc.dylib` or `aoc.dll`) declared by `include/aoc.h`, which
+//! is generated from this file by [`header`] and printed by `aoc header`.
+
+use std::{
+    any::Any,
+    ffi::c_char,
+    panic::{self, AssertUnwindSafe},
+    slice, str,
+};
+
+use crate::days;
+
+/// What [`aoc_solve`] did.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    UnknownDay = 1,
+    UnknownPart = 2,
+    ParseError = 3,
+    BufferTooSmall = 4,
+    InvalidArgument = 5,
+    Panic = 6,
+}
+
+impl Status {
+    const ALL: [Status; 7] = [
+        Status::Ok,
+        Status::UnknownDay,
+        Status::UnknownPart,
+        Status::ParseError,
+        Status::BufferTooSmall,
+        Status::InvalidArgument,
+        Status::Panic,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnknownDay => "AOC_UNKNOWN_DAY",
+            Status::UnknownPart => "AOC_UNKNOWN_PART",
+            Status::ParseError => "AOC_PARSE_ERROR",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::InvalidArgument => "AOC_INVALID_ARGUMENT",
+            Status::Panic => "AOC_PANIC",
+        }
+    }
+
+    fn doc(self) -> &'static str {
+        match self {
+            Status::Ok => "The answer was written to `out`.",
+            Status::UnknownDay => "There is no solution for the day.",
+            Status::UnknownPart => "The part is not 1 or 2.",
+            Status::ParseError => "The input is malformed, or not UTF-8; `out` says where.",
+            Status::BufferTooSmall => "The answer and its NUL do not fit in `out`.",
+            Status::InvalidArgument => "A buffer is null but its size is not 0.",
+            Status::Panic => "The solver panicked; `out` has the message.",
+        }
+    }
+}
+
+/// Solves `part` of `day` on the `input_len` bytes of UTF-8 at `input`, and writes the answer to
+/// `out` as a NUL-terminated string, as `aoc run` prints it. For a parse error or a panic the
+/// message is written instead, cut short if it does not fit. Unless it is null, `out_len` is set
+/// to the length of the answer or message without its NUL, so a buffer that was too small can be
+/// replaced by one of `*out_len + 1` bytes.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `out` to `out_cap` writable bytes, either
+/// of which may be null if its size is 0. `out_len` must be null or point to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const c_char,
+    input_len: usize,
+    out: *mut c_char,
+    out_cap: usize,
+    out_len: *mut usize,
+) -> Status {
+    if (input.is_null() && input_len > 0) || (out.is_null() && out_cap > 0) {
+        return Status::InvalidArgument;
+    }
+    let input = match input_len {
+        0 => &[][..],
+        _ => unsafe { slice::from_raw_parts(input.cast::<u8>(), input_len) },
+    };
+    let out = match out_cap {
+        0 => &mut [][..],
+        _ => unsafe { slice::from_raw_parts_mut(out.cast::<u8>(), out_cap) },
+    };
+
+    let (status, text) = solve(day, part, input);
+    if !out_len.is_null() {
+        unsafe { *out_len = text.len() };
+    }
+    if status == Status::Ok && text.len() >= out.len() {
+        write(out, "");
+        return Status::BufferTooSmall;
+    }
+    write(out, &text);
+    status
+}
+
+/// The status of solving `part` of `day`, and the answer or what went wrong.
+fn solve(day: u32, part: u32, input: &[u8]) -> (Status, String) {
+    let Some(solution) = u8::try_from(day).ok().and_then(days::get) else {
+        return (Status::UnknownDay, format!("no solution for day {day}"));
+    };
+    if !matches!(part, 1 | 2) {
+        return (
+            Status::UnknownPart,
+            format!("part must be 1 or 2, got {part}"),
+        );
+    }
+    let input = match str::from_utf8(input) {
+        Ok(input) => input,
+        Err(err) => return (Status::ParseError, format!("day {day}: {err}")),
+    };
+    guarded(|| match solution.parse(input) {
+        Ok(parsed) => (Status::Ok, solution.solve(&parsed, part as u8).to_string()),
+        Err(err) => (Status::ParseError, err.to_string()),
+    })
+}
+
+/// Runs `f`, turning a panic into [`Status::Panic`], as unwinding into C is undefined.
+fn guarded(f: impl FnOnce() -> (Status, String)) -> (Status, String) {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| (Status::Panic, format!("panicked: {}", message(&*payload))))
+}
+
+/// The message a panic was started with.
+fn message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown cause", String::as_str),
+    }
+}
+
+/// Copies as much of `text` to `out` as fits with a NUL after it.
+fn write(out: &mut [u8], text: &str) {
+    let Some(room) = out.len().checked_sub(1) else {
+        return;
+    };
+    let len = text.len().min(room);
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+    out[len] = 0;
+}
+
+/// The C header declaring [`aoc_solve`] and its statuses.
+pub fn header() -> String {
+    let statuses: String = Status::ALL
+        .iter()
+        .map(|&status| {
+            format!(
+                "    /* {} */\n    {} = {},\n",
+                status.doc(),
+                status.name(),
+                status as i32
+            )
+        })
+        .collect();
+    format!(
+        r#"/* Generated from src/ffi.rs by `aoc header`, do not edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {{
+#endif
+
+typedef enum aoc_status {{
+{statuses}}} aoc_status;
+
+/*
+ * Solves `part` of `day` on the `input_len` bytes of UTF-8 at `input`, and writes the answer to
+ * `out` as a NUL-terminated string. For a parse error or a panic the message is written instead,
+ * cut short if it does not fit. Unless it is null, `out_len` is set to the length of the answer
+ * or message without its NUL, so a buffer that was too small can be replaced by one of
+ * `*out_len + 1` bytes. `input` and `out` may be null if their size is 0.
+ */
+aoc_status aoc_solve(uint32_t day, uint32_t part, const char *input, size_t input_len, char *out,
+                     size_t out_cap, size_t *out_len);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::{aoc_solve, guarded, header, Status};
+
+    /// Calls [`aoc_solve`] with a buffer of `cap` bytes.
+    fn solve(day: u32, part: u32, input: &str, cap: usize) -> (Status, String, usize) {
+        let mut out = vec![0xff_u8; cap];
+        let mut len = 0;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr().cast(),
+                input.len(),
+                out.as_mut_ptr().cast(),
+                cap,
+                &mut len,
+            )
+        };
+        let end = out.iter().position(|&b| b == 0).unwrap_or(cap);
+        (
+            status,
+            String::from_utf8_lossy(&out[..end]).into_owned(),
+            len,
+        )
+    }
+
+    #[test]
+    fn test() {
+        let rounds = "A Y\nB X\nC Z\n";
+        assert_eq!((Status::Ok, "15".to_owned(), 2), solve(2, 1, rounds, 3));
+        assert_eq!((Status::Ok, "12".to_owned(), 2), solve(2, 2, rounds, 64));
+        assert_eq!(
+            (Status::BufferTooSmall, String::new(), 2),
+            solve(2, 1, rounds, 2)
+        );
+        assert_eq!(Status::UnknownDay, solve(25, 1, rounds, 64).0);
+        assert_eq!(Status::UnknownDay, solve(258, 1, rounds, 64).0);
+        assert_eq!(Status::UnknownPart, solve(2, 3, rounds, 64).0);
+
+        // the message is cut short to fit, but its length is whole
+        let message = "day 2, line 1, column 1: expected one of `A`, `B`, `C`, found `Q`";
+        assert_eq!(
+            (Status::ParseError, message[..9].to_owned(), message.len()),
+            solve(2, 1, "Q Y\n", 10)
+        );
+        let status =
+            unsafe { aoc_solve(2, 1, ptr::null(), 3, ptr::null_mut(), 0, ptr::null_mut()) };
+        assert_eq!(Status::InvalidArgument, status);
+        let status =
+            unsafe { aoc_solve(2, 1, ptr::null(), 0, ptr::null_mut(), 0, ptr::null_mut()) };
+        assert_eq!(Status::BufferTooSmall, status);
+
+        let (status, message) = guarded(|| panic!("no {}", "beacon"));
+        assert_eq!((Status::Panic, "panicked: no beacon"), (status, &*message));
+
+        assert_eq!(
+            include_str!("../include/aoc.h"),
+            header(),
+            "the header is out of date, run `cargo run -- header > include/aoc.h`"
+        );
+    }
+}
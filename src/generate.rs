@@ -0,0 +1,123 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo-random number generator (SplitMix64), so a seed always gives the same
+/// generated input.
+pub struct Rng(u64);
+
+/// Integers [`Rng::range`] can pick.
+pub trait Sample: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(n: i128) -> Self;
+}
+
+macro_rules! impl_sample {
+    ($($t:ty),*) => {
+        $(impl Sample for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(n: i128) -> Self {
+                n as $t
+            }
+        })*
+    };
+}
+
+impl_sample!(u8, i32, i64, isize, u64, usize);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range<T: Sample>(&mut self, range: RangeInclusive<T>) -> T {
+        let (lo, hi) = (range.start().to_i128(), range.end().to_i128());
+        assert!(lo <= hi, "cannot pick from an empty range");
+        let span = (hi - lo + 1) as u128;
+        T::from_i128(lo + (self.next_u64() as u128 % span) as i128)
+    }
+
+    /// An index below `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..=n - 1)
+    }
+
+    /// True `numerator` times out of `denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+    use crate::{
+        days::{day15, DAYS},
+        point::Point,
+    };
+
+    #[test]
+    fn test() {
+        let mut rng = Rng::new(7);
+        let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        let mut rng = Rng::new(7);
+        assert!((0..4).map(|_| rng.next_u64()).eq(first));
+        assert!((0..1000).all(|_| (-3..=3).contains(&rng.range(-3..=3))));
+
+        // every generated input is accepted and solved
+        for day in DAYS.iter().filter(|day| day.day != 15) {
+            for seed in 0..4 {
+                let input = day.generate(seed, 12);
+                let parsed = day
+                    .parse(&input)
+                    .unwrap_or_else(|err| panic!("seed {seed}: {err}\n{input}"));
+                day.solve(&parsed, 1);
+                day.solve(&parsed, 2);
+            }
+        }
+
+        // day 15 leaves exactly one position for the distress beacon
+        for seed in 0..4 {
+            let input = day15::generate_within(&mut Rng::new(seed), 12, 50);
+            let scans = day15::parse(&input).unwrap();
+            day15::part1(&scans, 25);
+            let frequency = day15::part2(&scans, 50).unwrap();
+            let free = Point::new(
+                (frequency / 4000000) as isize,
+                (frequency % 4000000) as isize,
+            );
+            assert!(free.x <= 50 && free.y <= 50);
+            let uncovered = |point: Point| {
+                scans
+                    .iter()
+                    .all(|scan| scan.sensor.manhattan(point) > scan.radius())
+            };
+            assert!(uncovered(free));
+            let open = (0..=50)
+                .flat_map(|x| (0..=50).map(move |y| Point::new(x, y)))
+                .filter(|&p| uncovered(p))
+                .count();
+            assert_eq!(1, open, "seed {seed}:\n{input}");
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse::Lines, point::Point, ParseError};
+
+/// A rectangular grid of cells, with the origin in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a map with a character per cell and a line per row, every row as wide as the first.
+    /// `cell` turns the character at `(x, y)` into a cell, or says what it expected instead.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(Point, char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(day, input);
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (y, mut line) in lines.by_ref().enumerate() {
+            let mut x = 0;
+            while Some(x) != width {
+                match line.peek() {
+                    Some(c) => {
+                        let pos = Point::new(x as isize, y as isize);
+                        cells.push(cell(pos, c).map_err(|expected| line.error(expected))?);
+                        line.next_char();
+                        x += 1;
+                    }
+                    None => match width {
+                        None if x > 0 => break,
+                        None => return Err(line.error("a cell")),
+                        Some(width) => return Err(line.error(format!("a row {width} cells wide"))),
+                    },
+                }
+            }
+            line.end()?;
+            width = Some(x);
+            height += 1;
+        }
+
+        let width = width.ok_or_else(|| lines.end_of_input("a row"))?;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the cell at `pos`, returning the old one, or `None` if `pos` is outside the grid.
+    pub fn set(&mut self, pos: Point, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as isize;
+        (0..self.height as isize).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions left, right, above and below `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbours4().filter(|&pos| self.contains(pos))
+    }
+
+    /// The positions around `pos`, diagonals included, that are inside the grid.
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbours8().filter(|&pos| self.contains(pos))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the grid"))
+    }
+}
+
+/// The grid as a map, each cell displayed in turn with a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::point::Point;
+
+    #[test]
+    fn test() {
+        let digit = |_, c: char| c.to_digit(10).ok_or_else(|| "a digit".to_owned());
+        let mut grid = Grid::parse(8, "123\n456\n", digit).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!("123\n456\n", grid.to_string());
+        assert_eq!(Some(&6), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(0, 2)));
+
+        assert_eq!(Some(5), grid.set(Point::new(1, 1), 0));
+        assert_eq!(None, grid.set(Point::new(1, 2), 0));
+        grid[Point::ORIGIN] = 9;
+        assert_eq!(&[9, 2, 3], grid.row(0));
+        assert!(grid.column(1).copied().eq([2, 0]));
+        assert!(grid.column(2).rev().copied().eq([6, 3]));
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(
+            vec![&[9, 2, 3][..], &[4, 0, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "9..\n...\n",
+            grid.map(|&n| if n == 9 { '9' } else { '.' }).to_string()
+        );
+
+        let points = |points: &[(isize, isize)]| -> Vec<Point> {
+            points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+        };
+        assert_eq!(
+            points(&[(1, 0), (0, 1)]),
+            grid.neighbours4(Point::ORIGIN).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            points(&[(0, 0), (2, 0), (1, 1)]),
+            grid.neighbours4(Point::new(1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            points(&[(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]),
+            grid.neighbours8(Point::new(1, 1)).collect::<Vec<_>>()
+        );
+        assert!(!grid.contains(Point::new(-1, 0)));
+        assert_eq!(6, grid.positions().count());
+        assert_eq!(Some((Point::new(2, 1), &6)), grid.iter().last());
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..\n");
+
+        for (input, err) in [
+            ("", "line 1, column 1: expected a row, found end of input"),
+            (
+                "\n1",
+                "line 1, column 1: expected a cell, found end of line",
+            ),
+            (
+                "12\n1",
+                "line 2, column 2: expected a row 2 cells wide, found end of line",
+            ),
+            (
+                "12\n123",
+                "line 2, column 3: expected end of line, found `3`",
+            ),
+            ("12\n1x", "line 2, column 2: expected a digit, found `x`"),
+        ] {
+            let message = Grid::parse(8, input, digit).unwrap_err().to_string();
+            assert_eq!(format!("day 8, {err}"), message);
+        }
+    }
+}
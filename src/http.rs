@@ -0,0 +1,143 @@
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    /// The value of the first header called `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Splits a raw response: status line, headers and body.
+    pub fn parse(raw: &[u8]) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_owned());
+        let raw = String::from_utf8_lossy(raw);
+        let (head, body) = raw
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| invalid("response has no end of headers"))?;
+        let mut lines = head.split("\r\n");
+
+        let status = lines
+            .next()
+            .filter(|line| line.starts_with("HTTP/"))
+            .and_then(|line| line.split(' ').nth(1))
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| invalid("response has no status line"))?;
+        let headers = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+            .collect();
+
+        Ok(Response {
+            status,
+            headers,
+            body: body.to_owned(),
+        })
+    }
+}
+
+/// Something that can send a GET request, so the fetcher can be pointed at other servers.
+pub trait Http {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response>;
+}
+
+/// The backend for `url`: plain `http://` is spoken directly, anything else goes through `curl`.
+pub fn backend(url: &str) -> Box<dyn Http> {
+    if url.starts_with("http://") {
+        Box::new(Plain {
+            timeout: Duration::from_secs(30),
+        })
+    } else {
+        Box::new(Curl)
+    }
+}
+
+/// HTTP/1.0 over a TCP connection, which is all a local server needs.
+pub struct Plain {
+    pub timeout: Duration,
+}
+
+impl Http for Plain {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("not an http:// URL: {url}"),
+            )
+        })?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+        let addr = if host.contains(':') {
+            host.to_owned()
+        } else {
+            format!("{host}:80")
+        };
+
+        let mut stream = TcpStream::connect(addr)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        // HTTP/1.0 keeps the server from chunking the body and closes the connection after it.
+        let mut request = format!("GET {path} HTTP/1.0\r\nHost: {host}\r\n");
+        for (key, value) in headers {
+            request += &format!("{key}: {value}\r\n");
+        }
+        request += "\r\n";
+        stream.write_all(request.as_bytes())?;
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw)?;
+        Response::parse(&raw)
+    }
+}
+
+/// The `curl` command line tool, for HTTPS.
+pub struct Curl;
+
+impl Http for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        // The headers go through stdin, so the session cookie never shows up in the process list.
+        let mut curl = Command::new("curl")
+            .args([
+                "--silent",
+                "--show-error",
+                "--include",
+                "--header",
+                "@-",
+                url,
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| io::Error::new(err.kind(), format!("could not run curl: {err}")))?;
+
+        let mut stdin = curl.stdin.take().unwrap();
+        for (key, value) in headers {
+            writeln!(stdin, "{key}: {value}")?;
+        }
+        drop(stdin);
+
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            let err = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::other(format!("curl failed: {}", err.trim())));
+        }
+        Response::parse(&output.stdout)
+    }
+}
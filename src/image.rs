@@ -0,0 +1,232 @@
+//! Pictures of the puzzles' maps in formats that take nothing but a few lines to write: binary
+//! PPM for grids, a pixel per cell, and SVG for shapes too big to draw cell by cell.
+
+use std::fmt::Write;
+
+use crate::{
+    days::{day12, day14, day15, day8},
+    grid::Grid,
+    point::Point,
+    ParseError,
+};
+
+/// The days that can be drawn.
+pub const DAYS: &[u8] = &[8, 12, 14, 15];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// The colour `n / d` of the way from this one to `to`.
+    pub fn mix(self, to: Rgb, n: usize, d: usize) -> Rgb {
+        let channel = |from: u8, to: u8| {
+            let (from, to) = (from as isize, to as isize);
+            (from + (to - from) * n as isize / d.max(1) as isize) as u8
+        };
+        Rgb(
+            channel(self.0, to.0),
+            channel(self.1, to.1),
+            channel(self.2, to.2),
+        )
+    }
+}
+
+/// A picture in the format that suits it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    Ppm(Vec<u8>),
+    Svg(String),
+}
+
+impl Image {
+    /// The file extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Ppm(_) => "ppm",
+            Image::Svg(_) => "svg",
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Image::Ppm(bytes) => bytes,
+            Image::Svg(text) => text.into_bytes(),
+        }
+    }
+}
+
+/// What to draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Whose state to draw, for the days where the parts differ.
+    pub part: u8,
+    /// The side of a cell in pixels, for grids.
+    pub scale: usize,
+    /// The corners of the area to draw, for shapes; everything by default.
+    pub view: Option<(Point, Point)>,
+}
+
+/// The picture of `day`, a day in [`DAYS`], on `input`:
+/// - day 8: the forest by height, trees visible from outside in green
+/// - day 12: the heightmap by elevation, with the shortest climb of the part in red
+/// - day 14: the cave with the sand at rest at the end of the part
+/// - day 15: the sensors' ranges as diamonds, with the sensors and beacons
+pub fn export(day: u8, input: &str, options: &Options) -> Result<Image, ParseError> {
+    let part2 = options.part == 2;
+    let grid = match day {
+        8 => day8::image(&day8::parse(input)?),
+        12 => day12::image(&day12::parse(input)?, part2),
+        14 => day14::image(&day14::parse(input)?, part2),
+        15 => {
+            return Ok(Image::Svg(day15::image(
+                &day15::parse(input)?,
+                options.view,
+            )))
+        }
+        _ => unreachable!("day {day} cannot be drawn"),
+    };
+    Ok(Image::Ppm(ppm(&grid, options.scale)))
+}
+
+/// A binary PPM of `grid`, each cell a square `scale` pixels wide.
+pub fn ppm(grid: &Grid<Rgb>, scale: usize) -> Vec<u8> {
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let mut bytes = format!("P6\n{width} {height}\n255\n").into_bytes();
+    for y in 0..grid.height() {
+        let row: Vec<u8> = grid
+            .row(y)
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b].repeat(scale))
+            .collect();
+        for _ in 0..scale {
+            bytes.extend_from_slice(&row);
+        }
+    }
+    bytes
+}
+
+/// An SVG drawing of the cells from `min` to `max`, both included, where the cell at `(x, y)`
+/// is the unit square from `(x, y)` to `(x + 1, y + 1)`. Whatever is outside is clipped.
+#[derive(Debug, Clone)]
+pub struct Svg {
+    min: Point,
+    max: Point,
+    shapes: String,
+}
+
+impl Svg {
+    /// Pixels on the longest side of the drawing, whatever the cells it spans.
+    const SIZE: isize = 800;
+
+    pub fn new(min: Point, max: Point) -> Self {
+        Svg {
+            min,
+            max,
+            shapes: String::new(),
+        }
+    }
+
+    /// Whether any of the cells from `min` to `max` are in the drawing.
+    pub fn overlaps(&self, min: Point, max: Point) -> bool {
+        min.x <= self.max.x && max.x >= self.min.x && min.y <= self.max.y && max.y >= self.min.y
+    }
+
+    /// A polygon through the corners, each given as a cell and a fraction of it, so the centre of
+    /// the cell at `(x, y)` is `((x, y), (0.5, 0.5))`.
+    pub fn polygon(&mut self, corners: &[(Point, (f64, f64))], style: &str) {
+        let points: Vec<String> = corners
+            .iter()
+            .map(|&(cell, (dx, dy))| format!("{},{}", cell.x as f64 + dx, cell.y as f64 + dy))
+            .collect();
+        writeln!(
+            self.shapes,
+            r#"<polygon points="{}" style="{style}"/>"#,
+            points.join(" ")
+        )
+        .unwrap();
+    }
+
+    /// A dot on the centre of a cell, with a radius of `radius` pixels whatever the scale.
+    pub fn dot(&mut self, cell: Point, radius: f64, style: &str) {
+        writeln!(
+            self.shapes,
+            r#"<circle cx="{}" cy="{}" r="{}" style="{style}"/>"#,
+            cell.x as f64 + 0.5,
+            cell.y as f64 + 0.5,
+            radius * self.cells_per_pixel()
+        )
+        .unwrap();
+    }
+
+    fn cells_per_pixel(&self) -> f64 {
+        let cells = (self.max.x - self.min.x + 1).max(self.max.y - self.min.y + 1);
+        cells as f64 / Self::SIZE as f64
+    }
+
+    pub fn finish(self) -> String {
+        let (width, height) = (self.max.x - self.min.x + 1, self.max.y - self.min.y + 1);
+        let longest = width.max(height);
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" "#,
+                r#"viewBox="{} {} {} {}">"#,
+                "\n{}</svg>\n"
+            ),
+            (width * Self::SIZE / longest).max(1),
+            (height * Self::SIZE / longest).max(1),
+            self.min.x,
+            self.min.y,
+            width,
+            height,
+            self.shapes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{export, ppm, Image, Options, Rgb, Svg};
+    use crate::{grid::Grid, point::Point};
+
+    #[test]
+    fn test() {
+        let black = Rgb(0, 0, 0);
+        assert_eq!(Rgb(50, 100, 0), black.mix(Rgb(100, 200, 0), 1, 2));
+
+        let mut grid = Grid::new(2, 1, black);
+        grid[Point::new(1, 0)] = Rgb(1, 2, 3);
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            expected.extend([0, 0, 0, 0, 0, 0, 1, 2, 3, 1, 2, 3]);
+        }
+        assert_eq!(expected, ppm(&grid, 2));
+
+        let mut svg = Svg::new(Point::new(-2, 0), Point::new(1, 1));
+        assert!(svg.overlaps(Point::new(1, 1), Point::new(5, 5)));
+        assert!(!svg.overlaps(Point::new(2, -3), Point::new(5, 5)));
+        svg.dot(Point::new(0, 1), 100.0, "fill:red");
+        let svg = svg.finish();
+        assert!(svg.contains(r#"width="800" height="400" viewBox="-2 0 4 2""#));
+        assert!(svg.contains(r#"<circle cx="0.5" cy="1.5" r="0.5" style="fill:red"/>"#));
+
+        // the forest of day 8 is a pixel per tree
+        let options = Options {
+            part: 1,
+            scale: 1,
+            view: None,
+        };
+        let forest = export(8, "30373\n25512\n65332\n33549\n35390\n", &options).unwrap();
+        assert_eq!("ppm", forest.extension());
+        assert_eq!(
+            b"P6\n5 5\n255\n".len() + 5 * 5 * 3,
+            forest.into_bytes().len()
+        );
+
+        let input = "Sensor at x=8, y=7: closest beacon is at x=2, y=10\n";
+        let Image::Svg(svg) = export(15, input, &options).unwrap() else {
+            panic!("day 15 is not an SVG");
+        };
+        assert!(svg.contains(r#"viewBox="-1 -2 19 19""#));
+        assert!(svg.contains(r#"<polygon points="8.5,-2 18,7.5 8.5,17 -1,7.5""#));
+    }
+}
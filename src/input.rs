@@ -0,0 +1,105 @@
+use std::{
+    borrow::Cow,
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use aoc::days::Day;
+
+use crate::fetch;
+
+/// Where a day's input comes from.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    File(String),
+    Stdin,
+    /// Fetched with `aoc fetch` into the data directory.
+    Cached(PathBuf),
+    Committed,
+}
+
+impl Source {
+    /// `path` if given (`-` for stdin), else the fetched input if there is one, else the
+    /// committed input.
+    pub fn find(day: u8, path: Option<&str>) -> Self {
+        let cached = fetch::data_dir().map(|dir| fetch::cache_path(&dir, day));
+        Self::choose(path, cached.as_deref())
+    }
+
+    fn choose(path: Option<&str>, cached: Option<&Path>) -> Self {
+        match path {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_owned()),
+            None => match cached {
+                Some(cached) if cached.is_file() => Source::Cached(cached.to_owned()),
+                _ => Source::Committed,
+            },
+        }
+    }
+
+    /// The name answers for this input are stored under: `committed`, `cached`, `stdin`, or the
+    /// file name without extension.
+    pub fn name(&self) -> String {
+        match self {
+            Source::File(path) => Path::new(path).file_stem().map_or_else(
+                || path.to_owned(),
+                |stem| stem.to_string_lossy().into_owned(),
+            ),
+            Source::Stdin => "stdin".to_owned(),
+            Source::Cached(_) => "cached".to_owned(),
+            Source::Committed => "committed".to_owned(),
+        }
+    }
+
+    pub fn load(&self, day: &Day) -> io::Result<Cow<'static, str>> {
+        match self {
+            Source::File(path) => fs::read_to_string(path).map(Cow::Owned),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+            Source::Cached(path) => fs::read_to_string(path).map(Cow::Owned),
+            Source::Committed => Ok(Cow::Borrowed(day.input)),
+        }
+    }
+
+    /// The input to read as it goes, for inputs too big to [`load`](Self::load).
+    pub fn open(&self, day: &Day) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::File(path) => Box::new(BufReader::new(fs::File::open(path)?)),
+            Source::Cached(path) => Box::new(BufReader::new(fs::File::open(path)?)),
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::Committed => Box::new(day.input.as_bytes()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::Source;
+
+    #[test]
+    fn test() {
+        let cargo_toml = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let missing = Path::new(env!("CARGO_MANIFEST_DIR")).join("day1.txt");
+
+        assert_eq!(Source::Stdin, Source::choose(Some("-"), Some(&cargo_toml)));
+        assert_eq!(
+            Source::Cached(cargo_toml.clone()),
+            Source::choose(None, Some(&cargo_toml))
+        );
+        assert_eq!(Source::Committed, Source::choose(None, Some(&missing)));
+        assert_eq!(Source::Committed, Source::choose(None, None));
+
+        assert_eq!("committed", Source::Committed.name());
+        assert_eq!("stdin", Source::Stdin.name());
+        assert_eq!(
+            "day9-big",
+            Source::File("inputs/day9-big.txt".to_owned()).name()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    iter::Sum,
+    ops::{Range, Sub},
+};
+
+/// A set of values stored as disjoint half-open ranges, kept sorted. Ranges that touch are merged,
+/// so there is always a gap between two of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Intervals<T>(Vec<Range<T>>);
+
+impl<T> Intervals<T> {
+    pub fn new() -> Self {
+        Intervals(Vec::new())
+    }
+
+    /// The ranges in the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> Default for Intervals<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> Intervals<T> {
+    pub fn add(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // the ranges overlapping or touching `range` are replaced by their union with it
+        let i = self.0.partition_point(|r| r.end < range.start);
+        let j = self.0.partition_point(|r| r.start <= range.end);
+        let range = if i < j {
+            range.start.min(self.0[i].start)..range.end.max(self.0[j - 1].end)
+        } else {
+            range
+        };
+        self.0.splice(i..j, [range]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // the ranges overlapping `range` are replaced by what sticks out of it on either side
+        let i = self.0.partition_point(|r| r.end <= range.start);
+        let j = self.0.partition_point(|r| r.start < range.end);
+        if i >= j {
+            return;
+        }
+        let before = self.0[i].start..range.start;
+        let after = range.end..self.0[j - 1].end;
+        self.0
+            .splice(i..j, [before, after].into_iter().filter(|r| !r.is_empty()));
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.0.partition_point(|r| r.end <= value);
+        self.0.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.0 {
+            union.add(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.0.iter().peekable(), other.0.iter().peekable());
+        let mut intersection = Vec::new();
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.start.max(y.start)..x.end.min(y.end);
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+            // whichever ends first cannot overlap anything else
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Intervals(intersection)
+    }
+
+    /// The values of the set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.0 {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// The values of `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        Intervals::from(bounds).difference(self)
+    }
+
+    /// The ranges between those of the set, from its lowest to its highest value.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.0.windows(2).map(|pair| pair[0].end..pair[1].start)
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> T
+    where
+        T: Sub<Output = T> + Sum,
+    {
+        self.0.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for Intervals<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut intervals = Intervals::new();
+        intervals.add(range);
+        intervals
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for Intervals<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut intervals = Intervals::new();
+        for range in ranges {
+            intervals.add(range);
+        }
+        intervals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, ops::Range};
+
+    use super::Intervals;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test() {
+        let mut set: Intervals<isize> = [0..3, 5..7, 3..4, 10..10].into_iter().collect();
+        assert_eq!(&[0..4, 5..7], set.ranges());
+        assert_eq!(6, set.len());
+        assert!(set.contains(3) && !set.contains(4) && !set.contains(-1));
+        assert_eq!(vec![4..5], set.gaps().collect::<Vec<_>>());
+        assert_eq!(&[-2..0, 4..5, 7..8], set.complement(-2..8).ranges());
+
+        set.remove(2..6);
+        assert_eq!(&[0..2, 6..7], set.ranges());
+        set.add(2..6);
+        assert_eq!(&Intervals::from(0..7), &set);
+        set.remove(-5..0);
+        set.remove(7..9);
+        assert_eq!(&Intervals::from(0..7), &set);
+
+        // every operation agrees with the same one on a set of the values
+        let mut rng = Rng::new(14);
+        let values = |set: &Intervals<isize>| -> BTreeSet<isize> {
+            set.ranges().iter().cloned().flatten().collect()
+        };
+        let range = |rng: &mut Rng| -> Range<isize> {
+            let start = rng.range(-20..=20);
+            start..start + rng.range(-2..=8)
+        };
+        for _ in 0..2000 {
+            let mut a = Intervals::new();
+            let mut b = Intervals::new();
+            let mut expected = BTreeSet::new();
+            for _ in 0..rng.range(0..=6) {
+                let r = range(&mut rng);
+                if rng.chance(2, 3) {
+                    a.add(r.clone());
+                    expected.extend(r);
+                } else {
+                    a.remove(r.clone());
+                    expected.retain(|v| !r.contains(v));
+                }
+                b.add(range(&mut rng));
+            }
+
+            assert_eq!(expected, values(&a));
+            assert!(a.ranges().windows(2).all(|w| w[0].end < w[1].start));
+            assert!(a.ranges().iter().all(|r| !r.is_empty()));
+            assert_eq!(expected.len() as isize, a.len());
+            assert!((-30..30).all(|v| a.contains(v) == expected.contains(&v)));
+
+            let b_values = values(&b);
+            assert_eq!(&expected | &b_values, values(&a.union(&b)));
+            assert_eq!(&expected & &b_values, values(&a.intersection(&b)));
+            assert_eq!(&expected - &b_values, values(&a.difference(&b)));
+            let bounds: BTreeSet<isize> = (-10..10).collect();
+            assert_eq!(&bounds - &expected, values(&a.complement(-10..10)));
+
+            let (lo, hi) = (expected.first(), expected.last());
+            let between: BTreeSet<isize> = lo.map_or(0..0, |&lo| lo..*hi.unwrap()).collect();
+            let gaps: BTreeSet<isize> = a.gaps().flatten().collect();
+            assert_eq!(&between - &expected, gaps);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+pub mod allocations;
+pub mod animate;
+pub mod days;
+pub mod ffi;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod intervals;
+pub mod oracle;
+pub mod parse;
+pub mod point;
+pub mod repl;
+pub mod search;
+mod solution;
+pub mod stream;
+pub mod trace;
+
+pub use parse::ParseError;
+pub use solution::{Answer, Solution};
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: allocations::Counting = allocations::Counting;
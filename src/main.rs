@@ -0,0 +1,419 @@
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, BufRead, IsTerminal, Write},
+    net::TcpListener,
+    path::Path,
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use answers::Answers;
+use aoc::{
+    days::{self, Day},
+    image, oracle,
+    parse::{Cursor, ReadError},
+    Answer,
+};
+use cli::{Animate, Command, Trace};
+use fetch::FetchError;
+use input::Source;
+
+mod answers;
+mod bench;
+mod cli;
+mod fetch;
+mod http;
+mod input;
+mod scaffold;
+mod serve;
+mod summary;
+mod terminal;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc::allocations::Counting = aoc::allocations::Counting;
+
+/// Prints an answer as `day <n> part <p>: <answer>`, multi-line answers start on the next line.
+fn print_answer(day: u8, part: u8, answer: &Answer) {
+    let answer = answer.to_string();
+    let answer = answer.trim_matches('\n');
+    if answer.contains('\n') {
+        println!("day {day} part {part}:\n{answer}");
+    } else {
+        println!("day {day} part {part}: {answer}");
+    }
+}
+
+fn load_input(solution: &Day, source: &Source) -> Result<Cow<'static, str>, String> {
+    source
+        .load(solution)
+        .map_err(|err| format!("could not read input for day {}: {err}", solution.day))
+}
+
+/// Runs `f` with the days' trace written where asked.
+fn traced(trace: Option<Trace>, f: impl FnOnce() -> Result<(), String>) -> Result<(), String> {
+    let sink: Box<dyn Write + Send> = match trace {
+        None => return f(),
+        Some(Trace::Stderr) => Box::new(io::stderr()),
+        Some(Trace::File(path)) => Box::new(io::BufWriter::new(
+            fs::File::create(&path).map_err(|err| format!("could not create {path}: {err}"))?,
+        )),
+    };
+    aoc::trace::set_sink(Some(sink));
+    let result = f();
+    if let Some(mut sink) = aoc::trace::set_sink(None) {
+        sink.flush()
+            .map_err(|err| format!("could not write the trace: {err}"))?;
+    }
+    result
+}
+
+fn run(days: &[u8], parts: &[u8], input: Option<&str>) -> Result<(), String> {
+    for &day in days {
+        let solution = days::get(day).unwrap();
+        let input = load_input(solution, &Source::find(day, input))?;
+        let parsed = solution.parse(&input).map_err(|err| err.to_string())?;
+        for &part in parts {
+            print_answer(day, part, &solution.solve(&parsed, part));
+        }
+    }
+    Ok(())
+}
+
+/// Solves `day` as its input is read, printing the answers once it has all been read.
+fn stream(day: u8, parts: &[u8], input: Option<&str>) -> Result<(), String> {
+    let solution = days::get(day).unwrap();
+    let could_not_read = |err| format!("could not read input for day {day}: {err}");
+    let reader = Source::find(day, input)
+        .open(solution)
+        .map_err(could_not_read)?;
+    let answers = aoc::stream::solve(day, reader).map_err(|err| match err {
+        ReadError::Io(err) => could_not_read(err),
+        ReadError::Parse(err) => err.to_string(),
+    })?;
+    for &part in parts {
+        print_answer(day, part, &answers[part as usize - 1]);
+    }
+    Ok(())
+}
+
+fn run_all(days: &[u8], parts: &[u8], jobs: Option<usize>, json: bool) -> Result<(), String> {
+    let days: Vec<&Day> = days.iter().map(|&day| days::get(day).unwrap()).collect();
+    let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    let summary = summary::run(&days, parts, jobs, |day| {
+        load_input(day, &Source::find(day.day, None))
+    });
+
+    if json {
+        println!("{}", summary::json(&summary));
+    } else {
+        for outcome in &summary.outcomes {
+            for (part, answer) in &outcome.answers {
+                print_answer(outcome.day, *part, answer);
+            }
+        }
+        print!("\n{}", summary::table(&summary));
+    }
+    match summary.failed() {
+        0 => Ok(()),
+        1 => Err("1 day failed".to_owned()),
+        n => Err(format!("{n} days failed")),
+    }
+}
+
+/// Plays the simulation of each part in the terminal, then prints the answers under the last frame.
+fn animate(day: u8, parts: &[u8], input: Option<&str>, options: &Animate) -> Result<(), String> {
+    let solution = days::get(day).unwrap();
+    let input = load_input(solution, &Source::find(day, input))?;
+    let parsed = solution.parse(&input).map_err(|err| err.to_string())?;
+    let mut terminal = terminal::Terminal::open()
+        .map_err(|err| format!("could not set up the terminal: {err}"))?;
+
+    let mut finished = Vec::new();
+    for &part in parts {
+        if !finished.is_empty() {
+            println!("press a key for part {part}");
+            terminal.wait_for_key();
+        }
+        let mut simulation =
+            aoc::animate::simulation(day, &input, part).map_err(|err| err.to_string())?;
+        let done = terminal
+            .animate(&mut *simulation, options)
+            .map_err(|err| format!("could not draw the animation: {err}"))?;
+        if !done {
+            break;
+        }
+        finished.push(part);
+    }
+    drop(terminal);
+
+    for part in finished {
+        print_answer(day, part, &solution.solve(&parsed, part));
+    }
+    Ok(())
+}
+
+fn bench(days: &[u8], input: Option<&str>, runs: usize, json: bool) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for &day in days {
+        let solution = days::get(day).unwrap();
+        let input = load_input(solution, &Source::find(day, input))?;
+        reports.push(bench::run(solution, &input, runs).map_err(|err| err.to_string())?);
+    }
+    if json {
+        println!("{}", bench::json(runs, &reports));
+    } else {
+        print!("{}", bench::table(&reports));
+    }
+    Ok(())
+}
+
+fn verify(days: &[u8], input: Option<&str>, answers_path: &str) -> Result<(), String> {
+    let answers = fs::read_to_string(answers_path)
+        .map_err(|err| format!("could not read {answers_path}: {err}"))
+        .and_then(|text| Answers::parse(&text).map_err(|err| format!("{answers_path}: {err}")))?;
+
+    let (mut ok, mut wrong, mut missing) = (0, 0, 0);
+    for &day in days {
+        let solution = days::get(day).unwrap();
+        let source = Source::find(day, input);
+        let name = source.name();
+        let input = load_input(solution, &source)?;
+        let parsed = solution.parse(&input).map_err(|err| err.to_string())?;
+        for part in 1..=2 {
+            let answer = solution.solve(&parsed, part).to_string();
+            let answer = answer.trim_matches('\n');
+            match answers.get(day, &name, part) {
+                Some(expected) if expected == answer => ok += 1,
+                Some(expected) => {
+                    wrong += 1;
+                    println!("day {day} part {part}: expected {expected}, got {answer}");
+                }
+                None => {
+                    missing += 1;
+                    println!("day {day} part {part}: no answer stored for input `{name}`");
+                }
+            }
+        }
+    }
+
+    println!("{ok} correct, {wrong} wrong, {missing} missing");
+    if wrong > 0 {
+        return Err(format!("answers differ from {answers_path}"));
+    }
+    Ok(())
+}
+
+fn fetch(day: u8, force: bool, base_url: Option<&str>) -> Result<(), String> {
+    let data_dir = fetch::data_dir().ok_or("no data directory, set AOC_DATA_DIR")?;
+    let path = fetch::cache_path(&data_dir, day);
+    if path.is_file() && !force {
+        println!("day {day}: already fetched to {}", path.display());
+        return Ok(());
+    }
+
+    let session = fetch::session().ok_or(FetchError::NoSession.to_string())?;
+    let env_url = env::var("AOC_BASE_URL").ok();
+    let base_url = base_url.or(env_url.as_deref()).unwrap_or(fetch::BASE_URL);
+    let input = fetch::fetch(&*http::backend(base_url), base_url, &session, day)
+        .map_err(|err| format!("could not fetch day {day}: {err}"))?;
+    fetch::save(&path, &input)
+        .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    println!("day {day}: fetched to {}", path.display());
+    Ok(())
+}
+
+fn diff(days: &[u8], seeds: u64, size: usize) -> Result<(), String> {
+    for &day in days {
+        let checked = oracle::differential(day, 0..seeds, size).map_err(|err| err.to_string())?;
+        println!("day {day}: {checked} answers agree with the oracle over {seeds} seeds");
+    }
+    Ok(())
+}
+
+fn image(
+    day: u8,
+    input: Option<&str>,
+    output: Option<&str>,
+    options: &image::Options,
+) -> Result<(), String> {
+    let solution = days::get(day).unwrap();
+    let input = load_input(solution, &Source::find(day, input))?;
+    let image = image::export(day, &input, options).map_err(|err| err.to_string())?;
+    let path = output.map_or_else(|| format!("day{day}.{}", image.extension()), str::to_owned);
+    if path == "-" {
+        io::stdout()
+            .write_all(&image.into_bytes())
+            .map_err(|err| format!("could not write the image: {err}"))?;
+    } else {
+        fs::write(&path, image.into_bytes())
+            .map_err(|err| format!("could not write {path}: {err}"))?;
+        eprintln!("day {day}: drawn to {path}");
+    }
+    Ok(())
+}
+
+fn new_day(day: u8, root: Option<&str>) -> Result<(), String> {
+    let root = Path::new(root.unwrap_or(env!("CARGO_MANIFEST_DIR")));
+    for path in scaffold::new_day(root, day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn repl(day: u8, input: Option<&str>) -> Result<(), String> {
+    let solution = days::get(day).unwrap();
+    let input = load_input(solution, &Source::find(day, input))?;
+    let session = aoc::repl::session(day, &input).map_err(|err| err.to_string())?;
+
+    let stdin = io::stdin();
+    let prompt = stdin.is_terminal();
+    if prompt {
+        println!("day {day}: `help` lists the commands, `quit` stops");
+    }
+    let mut line = String::new();
+    for n in 1.. {
+        if prompt {
+            print!("> ");
+            io::stdout().flush().ok();
+        }
+        line.clear();
+        let read = stdin.lock().read_line(&mut line);
+        if read.map_err(|err| format!("could not read a command: {err}"))? == 0 {
+            break;
+        }
+        match line.trim_end() {
+            "" => {}
+            "quit" | "exit" => break,
+            "help" => {
+                for command in session.help() {
+                    println!("{command}");
+                }
+                println!("quit: stop");
+            }
+            command => match session.query(&mut Cursor::new(day, n, command)) {
+                Ok(answer) => println!("{answer}"),
+                Err(err) => eprintln!(
+                    "error: column {}: expected {}, found {}",
+                    err.column, err.expected, err.found
+                ),
+            },
+        }
+    }
+    Ok(())
+}
+
+fn serve(port: u16, jobs: Option<usize>, max_body: usize, timeout: Duration) -> Result<(), String> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|err| format!("could not listen on port {port}: {err}"))?;
+    let limits = serve::Limits {
+        jobs: jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())),
+        max_body,
+        timeout,
+    };
+    println!("listening on http://{}", listener.local_addr().unwrap());
+    serve::serve(listener, limits).map_err(|err| format!("could not accept a connection: {err}"))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match cli::parse(&args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run {
+            days,
+            parts,
+            input,
+            animate: Some(options),
+            stream: _,
+            trace,
+        } => traced(trace, || {
+            animate(days[0], &parts, input.as_deref(), &options)
+        }),
+        Command::Run {
+            days,
+            parts,
+            input,
+            animate: None,
+            stream: true,
+            trace,
+        } => traced(trace, || stream(days[0], &parts, input.as_deref())),
+        Command::Run {
+            days,
+            parts,
+            input,
+            animate: None,
+            stream: false,
+            trace,
+        } => traced(trace, || run(&days, &parts, input.as_deref())),
+        Command::RunAll {
+            days,
+            parts,
+            jobs,
+            json,
+            trace,
+        } => traced(trace, || run_all(&days, &parts, jobs, json)),
+        Command::Bench {
+            days,
+            input,
+            runs,
+            json,
+        } => bench(&days, input.as_deref(), runs, json),
+        Command::Verify {
+            days,
+            input,
+            answers,
+        } => verify(&days, input.as_deref(), &answers),
+        Command::Fetch {
+            day,
+            force,
+            base_url,
+        } => fetch(day, force, base_url.as_deref()),
+        Command::Serve {
+            port,
+            jobs,
+            max_body,
+            timeout,
+        } => serve(port, jobs, max_body, timeout),
+        Command::Repl { day, input } => repl(day, input.as_deref()),
+        Command::New { day, root } => new_day(day, root.as_deref()),
+        Command::Header => {
+            print!("{}", aoc::ffi::header());
+            Ok(())
+        }
+        Command::Generate { day, seed, size } => {
+            print!("{}", days::get(day).unwrap().generate(seed, size));
+            Ok(())
+        }
+        Command::Diff { days, seeds, size } => diff(&days, seeds, size),
+        Command::Image {
+            day,
+            part,
+            input,
+            output,
+            scale,
+            view,
+        } => image(
+            day,
+            input.as_deref(),
+            output.as_deref(),
+            &image::Options { part, scale, view },
+        ),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Differential testing of the days whose shortcuts are hard to check by eye: each is run against
+//! a slow but obviously correct oracle on small generated inputs.
+
+use std::{fmt, ops::Range};
+
+use crate::{
+    days::{day11, day15, day16},
+    generate::Rng,
+    ParseError,
+};
+
+/// The days with an oracle.
+pub const DAYS: &[u8] = &[11, 15, 16];
+
+/// How far day 15's generated sensors are spread, small enough to mark every position.
+const DAY15_BOUND: isize = 40;
+
+/// Where a solution first gives a different answer than its oracle.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub day: u8,
+    pub seed: u64,
+    /// Which answer differs, like `part 2 after 20 rounds`.
+    pub answer: String,
+    pub oracle: String,
+    pub solution: String,
+    pub input: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} seed {}, {}: oracle says {}, solution says {}, on input:\n{}",
+            self.day, self.seed, self.answer, self.oracle, self.solution, self.input
+        )
+    }
+}
+
+/// Compares `day`'s solution with its oracle on an input generated from every seed, returning how
+/// many answers agreed or the first that did not. `day` must be one of [`DAYS`].
+pub fn differential(day: u8, seeds: Range<u64>, size: usize) -> Result<usize, Disagreement> {
+    let mut checked = 0;
+    for seed in seeds {
+        let mut rng = Rng::new(seed);
+        let input = match day {
+            11 => day11::generate(&mut rng, size),
+            15 => day15::generate_within(&mut rng, size, DAY15_BOUND),
+            16 => day16::generate(&mut rng, size),
+            _ => unreachable!("day {day} has no oracle"),
+        };
+        let answers = compare(day, &input).unwrap_or_else(|err| panic!("seed {seed}: {err}"));
+
+        for (answer, oracle, solution) in answers {
+            if oracle != solution {
+                return Err(Disagreement {
+                    day,
+                    seed,
+                    answer,
+                    oracle,
+                    solution,
+                    input,
+                });
+            }
+            checked += 1;
+        }
+    }
+    Ok(checked)
+}
+
+/// Every answer the oracle can check on `input`, as (which, oracle, solution).
+fn compare(day: u8, input: &str) -> Result<Vec<(String, String, String)>, ParseError> {
+    let mut answers = Vec::new();
+    let mut add = |answer: String, oracle: String, solution: String| {
+        answers.push((answer, oracle, solution));
+    };
+
+    match day {
+        15 => {
+            let scans = day15::parse(input)?;
+            for row in [0, DAY15_BOUND / 2, DAY15_BOUND] {
+                add(
+                    format!("part 1 in row {row}"),
+                    day15::oracle_part1(&scans, row).to_string(),
+                    day15::part1(&scans, row).to_string(),
+                );
+            }
+            let free = |frequency: Option<u64>| {
+                frequency.map_or("no free position".to_owned(), |f| f.to_string())
+            };
+            add(
+                "part 2".to_owned(),
+                free(day15::oracle_part2(&scans, DAY15_BOUND)),
+                free(day15::part2(&scans, DAY15_BOUND)),
+            );
+        }
+        16 => {
+            let volcano = day16::parse(input)?;
+            add(
+                "part 1".to_owned(),
+                day16::oracle_part1(&volcano).to_string(),
+                day16::part1(&volcano).to_string(),
+            );
+            add(
+                "part 2".to_owned(),
+                day16::oracle_part2(&volcano).to_string(),
+                day16::part2(&volcano).to_string(),
+            );
+        }
+        11 => {
+            let monkeys = day11::parse(input)?;
+            add(
+                "part 1".to_owned(),
+                day11::oracle(&monkeys, 20, true).unwrap().to_string(),
+                day11::part1(&monkeys).to_string(),
+            );
+            // exact worry levels soon get too big, so part 2 is checked for as long as they fit
+            for rounds in [1, 2, 5, 10, 20, 50, 100] {
+                let Some(oracle) = day11::oracle(&monkeys, rounds, false) else {
+                    break;
+                };
+                add(
+                    format!("part 2 after {rounds} rounds"),
+                    oracle.to_string(),
+                    day11::part2_after(&monkeys, rounds).to_string(),
+                );
+            }
+        }
+        _ => unreachable!("day {day} has no oracle"),
+    }
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{differential, DAYS};
+
+    #[test]
+    fn test() {
+        for &day in DAYS {
+            let checked = differential(day, 0..20, 10).unwrap_or_else(|err| panic!("{err}"));
+            assert!(checked >= 40, "day {day}: only {checked} answers checked");
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+/// A malformed puzzle input: where it went wrong and what the parser wanted to see there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line of the input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, found {}",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A position in one line of a puzzle input, which parsers advance as they read tokens.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    day: u8,
+    line: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(day: u8, line: usize, text: &'a str) -> Self {
+        Cursor {
+            day,
+            line,
+            text,
+            pos: 0,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// The whole line, regardless of how much has been read.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.line,
+            column: self.column(),
+            expected: expected.into(),
+            found: match self.peek() {
+                Some(c) => format!("`{c}`"),
+                None => "end of line".to_owned(),
+            },
+        }
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Skips `literal` if the line continues with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.pos += literal.len();
+        }
+        found
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{literal}`")))
+        }
+    }
+
+    /// Reads whichever of `options` comes next, trying them in order.
+    pub fn one_of(&mut self, options: &[&'static str]) -> Result<&'static str, ParseError> {
+        match options.iter().find(|option| self.eat(option)) {
+            Some(option) => Ok(option),
+            None => {
+                let options: Vec<_> = options.iter().map(|o| format!("`{o}`")).collect();
+                Err(self.error(format!("one of {}", options.join(", "))))
+            }
+        }
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Reads an optionally negative decimal number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = *self;
+        self.eat("-");
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let number = &start.rest()[..self.pos - start.pos];
+
+        match number.parse() {
+            Ok(number) if !digits.is_empty() => Ok(number),
+            _ => {
+                *self = start;
+                Err(start.error("a number"))
+            }
+        }
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+/// The lines of a puzzle input, each as a [`Cursor`].
+pub struct Lines<'a> {
+    day: u8,
+    lines: std::str::Lines<'a>,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Lines {
+            day,
+            lines: input.lines(),
+            line: 0,
+        }
+    }
+
+    /// The next line, which the input must still have.
+    pub fn expect_line(&mut self, expected: &str) -> Result<Cursor<'a>, ParseError> {
+        self.next().ok_or_else(|| self.end_of_input(expected))
+    }
+
+    /// An error for an input that stopped before `expected`.
+    pub fn end_of_input(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.line + 1,
+            column: 1,
+            expected: expected.into(),
+            found: "end of input".to_owned(),
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Cursor<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.line += 1;
+        Some(Cursor::new(self.day, self.line, text))
+    }
+}
+
+/// Why an input read as it goes could not be solved: reading failed, or it is malformed.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "could not read input: {err}"),
+            ReadError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+/// The lines of a puzzle input read from `reader` one at a time, so only the current line is
+/// held in memory. Lines end like they do for [`Lines`].
+pub struct ReadLines<R> {
+    day: u8,
+    reader: R,
+    buf: String,
+    line: usize,
+}
+
+impl<R: BufRead> ReadLines<R> {
+    pub fn new(day: u8, reader: R) -> Self {
+        ReadLines {
+            day,
+            reader,
+            buf: String::new(),
+            line: 0,
+        }
+    }
+
+    /// The next line, or `None` at the end of the input.
+    pub fn next_line(&mut self) -> Result<Option<Cursor<'_>>, ReadError> {
+        self.buf.clear();
+        if self.reader.read_line(&mut self.buf)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        if self.buf.ends_with('\n') {
+            self.buf.pop();
+            if self.buf.ends_with('\r') {
+                self.buf.pop();
+            }
+        }
+        Ok(Some(Cursor::new(self.day, self.line, &self.buf)))
+    }
+
+    /// An error for an input that stopped before `expected`.
+    pub fn end_of_input(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.line + 1,
+            column: 1,
+            expected: expected.into(),
+            found: "end of input".to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cursor, Lines, ReadError, ReadLines};
+
+    #[test]
+    fn test() {
+        let mut cursor = Cursor::new(4, 7, "2-4,6-x");
+        assert_eq!(Ok(2), cursor.number::<usize>());
+        assert_eq!(Ok(()), cursor.literal("-"));
+        assert_eq!(Ok(4), cursor.number::<usize>());
+        assert!(cursor.literal(",").is_ok() && cursor.number::<usize>().is_ok());
+        cursor.literal("-").unwrap();
+
+        let err = cursor.number::<usize>().unwrap_err();
+        assert_eq!((4, 7, 7), (err.day, err.line, err.column));
+        assert_eq!(
+            "day 4, line 7, column 7: expected a number, found `x`",
+            err.to_string()
+        );
+
+        let mut cursor = Cursor::new(15, 1, "x=-2, y");
+        cursor.literal("x=").unwrap();
+        assert_eq!(Ok(-2), cursor.number::<isize>());
+        assert_eq!(
+            "day 15, line 1, column 5: expected end of line, found `,`",
+            cursor.end().unwrap_err().to_string()
+        );
+
+        let mut cursor = Cursor::new(2, 1, "Q Y");
+        assert_eq!(
+            "day 2, line 1, column 1: expected one of `A`, `B`, `C`, found `Q`",
+            cursor.one_of(&["A", "B", "C"]).unwrap_err().to_string()
+        );
+
+        let mut lines = Lines::new(11, "Monkey 0:\n");
+        assert_eq!(1, lines.expect_line("a monkey").unwrap().line());
+        assert_eq!(
+            "day 11, line 2, column 1: expected a monkey, found end of input",
+            lines.expect_line("a monkey").unwrap_err().to_string()
+        );
+
+        // read lines end the same way
+        let input = "noop\r\naddx 3\n\nlast";
+        let mut read = ReadLines::new(10, input.as_bytes());
+        for line in Lines::new(10, input) {
+            let read = read.next_line().unwrap().unwrap();
+            assert_eq!((line.line(), line.text()), (read.line(), read.text()));
+        }
+        assert!(read.next_line().unwrap().is_none());
+        assert_eq!(
+            "day 10, line 5, column 1: expected an instruction, found end of input",
+            read.end_of_input("an instruction").to_string()
+        );
+        let mut read = ReadLines::new(10, &b"noop\n\xff\n"[..]);
+        assert!(read.next_line().is_ok());
+        assert!(matches!(read.next_line(), Err(ReadError::Io(_))));
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::{parse::Cursor, ParseError};
+
+/// A position on a map, or an offset between two, with `y` growing downwards like the rows of
+/// the puzzles' maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// A [`Point`] used as an offset.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// Steps along the axes between the two points.
+    pub fn manhattan(self, other: Point) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Steps between the two points when diagonal steps are allowed too.
+    pub fn chebyshev(self, other: Point) -> isize {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate clamped to -1, 0 or 1.
+    pub fn signum(self) -> Vec2 {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// One step towards `target`, moving at most one along each axis.
+    pub fn step_towards(self, target: Point) -> Point {
+        self + (target - self).signum()
+    }
+
+    /// The points left, right, above and below.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    /// The points around this one, diagonals included.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    /// Reads a point written as `x,y`.
+    pub fn parse_pair(line: &mut Cursor) -> Result<Self, ParseError> {
+        let x = line.number()?;
+        line.literal(",")?;
+        let y = line.number()?;
+        Ok(Point::new(x, y))
+    }
+
+    /// Reads a point written as `x=<x>, y=<y>`.
+    pub fn parse_labelled(line: &mut Cursor) -> Result<Self, ParseError> {
+        line.literal("x=")?;
+        let x = line.number()?;
+        line.literal(", y=")?;
+        let y = line.number()?;
+        Ok(Point::new(x, y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, n: isize) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+/// `x,y`, as [`Point::parse_pair`] reads it.
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A step along one of the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Up,
+        Direction::Down,
+    ];
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// A step to one of the eight surrounding points, named after the compass with north up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Row by row, like [`Grid::neighbours8`](crate::grid::Grid::neighbours8).
+    pub const ALL: [Direction8; 8] = [
+        Direction8::NW,
+        Direction8::N,
+        Direction8::NE,
+        Direction8::W,
+        Direction8::E,
+        Direction8::SW,
+        Direction8::S,
+        Direction8::SE,
+    ];
+
+    pub fn offset(self) -> Vec2 {
+        let (x, y) = match self {
+            Direction8::N => (0, -1),
+            Direction8::NE => (1, -1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, 1),
+            Direction8::S => (0, 1),
+            Direction8::SW => (-1, 1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, -1),
+        };
+        Point::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+            Direction::Right => Direction8::E,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, Point};
+    use crate::parse::Cursor;
+
+    #[test]
+    fn test() {
+        let (a, b) = (Point::new(2, -3), Point::new(-1, 1));
+        assert_eq!(Point::new(1, -2), a + b);
+        assert_eq!(Point::new(3, -4), a - b);
+        assert_eq!(Point::new(-4, 6), -a * 2);
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(b, c);
+
+        assert_eq!(7, a.manhattan(b));
+        assert_eq!(4, a.chebyshev(b));
+        assert_eq!(Point::new(1, -1), a.signum());
+        assert_eq!(Point::new(1, -2), a.step_towards(b));
+        assert_eq!(b, b.step_towards(b));
+        assert_eq!(4, a.neighbours4().filter(|&n| n.manhattan(a) == 1).count());
+        assert!(a.neighbours8().all(|n| n.chebyshev(a) == 1));
+
+        for direction in Direction::ALL {
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+            assert_eq!(direction.offset(), Direction8::from(direction).offset());
+        }
+
+        let mut line = Cursor::new(14, 1, "498,-4 -> x=2, y=18");
+        assert_eq!(Ok(Point::new(498, -4)), Point::parse_pair(&mut line));
+        line.literal(" -> ").unwrap();
+        assert_eq!(Ok(Point::new(2, 18)), Point::parse_labelled(&mut line));
+        assert_eq!("498,-4", Point::new(498, -4).to_string());
+        assert_eq!(
+            "day 14, line 1, column 3: expected `,`, found `;`",
+            Point::parse_pair(&mut Cursor::new(14, 1, "12;3"))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Questions about a day's parsed input, asked one command at a time, for looking into an input
+//! without writing a program for it.
+
+use crate::{
+    days::{day12, day13, day15, day16, day7},
+    parse::Cursor,
+    ParseError,
+};
+
+/// The days that can be explored.
+pub const DAYS: &[u8] = &[7, 12, 13, 15, 16];
+
+/// A day's parsed input, kept to answer commands about it.
+pub trait Session {
+    /// The commands understood, as `<usage>: <what it answers>`, one per line.
+    fn help(&self) -> &'static [&'static str];
+
+    /// The answer to the command on `line`; an error points at what could not be understood.
+    fn query(&self, line: &mut Cursor) -> Result<String, ParseError>;
+}
+
+/// The session for `day` on `input`, for a day in [`DAYS`].
+pub fn session(day: u8, input: &str) -> Result<Box<dyn Session>, ParseError> {
+    Ok(match day {
+        7 => Box::new(day7::Explorer::new(day7::parse_fs(input)?)),
+        12 => Box::new(day12::Explorer::new(day12::parse(input)?)),
+        13 => Box::new(day13::Explorer::new(day13::parse(input)?)),
+        15 => Box::new(day15::Explorer::new(day15::parse(input)?)),
+        16 => Box::new(day16::Explorer::new(day16::parse(input)?)),
+        _ => unreachable!("day {day} cannot be explored"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{session, DAYS};
+    use crate::{days, parse::Cursor};
+
+    #[test]
+    fn test() {
+        let ask = |day: u8, command: &str| {
+            let session = session(day, days::get(day).unwrap().input).unwrap();
+            session
+                .query(&mut Cursor::new(day, 1, command))
+                .map_err(|err| err.to_string())
+        };
+
+        assert_eq!(Ok("46233734".to_owned()), ask(7, "size /"));
+        assert_eq!(Ok("350 steps".to_owned()), ask(12, "path S E"));
+        assert_eq!(
+            Ok("the right order".to_owned()),
+            ask(13, "cmp [1,[2]] [[1],3]")
+        );
+        assert_eq!(Ok("4725496".to_owned()), ask(15, "row 2000000"));
+        assert_eq!(Ok("0 minutes".to_owned()), ask(16, "dist AA AA"));
+        assert_eq!(
+            Err("day 13, line 1, column 1: expected one of `cmp `, `pair `, found `s`".to_owned()),
+            ask(13, "size /")
+        );
+        for &day in DAYS {
+            assert!(!session(day, days::get(day).unwrap().input)
+                .unwrap()
+                .help()
+                .is_empty());
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! `aoc new`: the files of a new day, laid out like the others, and its place in the registry.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// Writes `src/days/day<n>.rs` with stubs for the parts and an example test,
+/// `src/days/day<n>-input.txt` left empty for the input, and registers the day in
+/// `src/days/mod.rs`, all under `root`. Returns the paths written. A day that already has any of
+/// them is left alone.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let days = root.join("src/days");
+    let module = days.join(format!("day{day}.rs"));
+    let input = days.join(format!("day{day}-input.txt"));
+    let registry = days.join("mod.rs");
+
+    for path in [&module, &input] {
+        if path.exists() {
+            return Err(format!("day {day} already exists: {}", path.display()));
+        }
+    }
+    let mod_rs = fs::read_to_string(&registry)
+        .map_err(|err| format!("could not read {}: {err}", registry.display()))?;
+    let mod_rs = register(&mod_rs, day)
+        .ok_or_else(|| format!("day {day} is already in {}", registry.display()))?;
+
+    create(&module, &module_source(day))?;
+    create(&input, "")?;
+    fs::write(&registry, mod_rs)
+        .map_err(|err| format!("could not write {}: {err}", registry.display()))?;
+    Ok(vec![module, input, registry])
+}
+
+/// Writes a file that must not exist yet.
+fn create(path: &Path, contents: &str) -> Result<(), String> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::AlreadyExists => format!("{} already exists", path.display()),
+            _ => format!("could not write {}: {err}", path.display()),
+        })
+}
+
+/// `mod_rs` with the day's `pub mod` among the others, in the order rustfmt keeps them, and the
+/// day in `DAYS` after the days before it. `None` if the day is already there.
+fn register(mod_rs: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{day};");
+    let entry = format!(
+        "    Day::new::<day{day}::Day{day}>(include_str!(\"day{day}-input.txt\"), day{day}::generate),"
+    );
+    let mut lines: Vec<&str> = mod_rs.lines().collect();
+    if lines.contains(&module.as_str()) {
+        return None;
+    }
+
+    let registered = |line: &str| -> Option<u8> {
+        let rest = line.strip_prefix("    Day::new::<day")?;
+        rest[..rest.find(':')?].parse().ok()
+    };
+    let last = lines.iter().rposition(|line| registered(line).is_some())?;
+    let at = lines[..=last]
+        .iter()
+        .position(|line| registered(line).is_some_and(|n| n > day))
+        .unwrap_or(last + 1);
+    lines.insert(at, &entry);
+
+    let name = |line: &str| -> Option<String> {
+        let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+        Some(name.to_owned())
+    };
+    let modules = lines
+        .iter()
+        .take_while(|line| line.starts_with("pub mod day"));
+    let at = modules.clone().position(|line| name(line) > name(&module));
+    lines.insert(at.unwrap_or(modules.count()), &module);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// The new day's module, with what every day needs and nothing solved yet.
+fn module_source(day: u8) -> String {
+    format!(
+        r#"use crate::{{generate::Rng, parse::Lines, Answer, ParseError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse(input)
+    }}
+
+    fn part1(lines: &Self::Input) -> Answer {{
+        part1(lines).into()
+    }}
+
+    fn part2(lines: &Self::Input) -> Answer {{
+        part2(lines).into()
+    }}
+}}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {{
+    Lines::new(Day{day}::DAY, input)
+        .map(|line| Ok(line.text().to_owned()))
+        .collect()
+}}
+
+pub fn part1(_lines: &[String]) -> usize {{
+    0
+}}
+
+pub fn part2(_lines: &[String]) -> usize {{
+    0
+}}
+
+/// A random, well-formed input; what `size` counts is up to the day.
+pub fn generate(_rng: &mut Rng, _size: usize) -> String {{
+    String::new()
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::{{parse, part1, part2}};
+    static TEST_INPUT: &str = r"";
+    #[test]
+    fn test() {{
+        let lines = parse(TEST_INPUT).unwrap();
+        assert_eq!(0, part1(&lines));
+        assert_eq!(0, part2(&lines));
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use aoc::days::DAYS;
+
+    use super::{new_day, register};
+
+    #[test]
+    fn test() {
+        let mod_rs = include_str!("days/mod.rs");
+        let last = DAYS.last().unwrap().day;
+        let next = last + 1;
+        let registered = register(mod_rs, next).unwrap();
+        let added: Vec<&str> = registered
+            .lines()
+            .filter(|line| !mod_rs.lines().any(|old| old == *line))
+            .collect();
+        assert_eq!(
+            vec![
+                format!("pub mod day{next};"),
+                format!(
+                    "    Day::new::<day{next}::Day{next}>(include_str!(\"day{next}-input.txt\"), \
+                     day{next}::generate),"
+                )
+            ],
+            added
+        );
+        let lines: Vec<&str> = registered.lines().collect();
+        let after = |line: &str| lines[lines.iter().position(|l| *l == line).unwrap() - 1];
+        assert_eq!(
+            format!("pub mod day{last};"),
+            after(&format!("pub mod day{next};"))
+        );
+        assert!(after(added[1]).contains(&format!("day{last}::Day{last}")));
+        assert!(register(mod_rs, 9).is_none());
+
+        // the registry's own test wants the days in order from day 1, with or without a gap
+        let days = |mod_rs: &str| -> Vec<u8> {
+            mod_rs
+                .lines()
+                .filter_map(|line| line.strip_prefix("    Day::new::<day")?.split(':').next())
+                .map(|day| day.parse().unwrap())
+                .collect()
+        };
+        for day in [next, next + 3] {
+            let days = days(&register(mod_rs, day).unwrap());
+            assert_eq!(Some(&1), days.first());
+            assert!(days.windows(2).all(|days| days[0] < days[1]));
+            assert_eq!(Some(&day), days.last());
+        }
+
+        // a day between the others goes between them
+        for day in [1, 5, 10] {
+            let without = mod_rs.replace(&format!("pub mod day{day};\n"), "").replace(
+                &format!(
+                    "    Day::new::<day{day}::Day{day}>(include_str!(\"day{day}-input.txt\"), \
+                     day{day}::generate),\n"
+                ),
+                "",
+            );
+            assert_ne!(mod_rs, without);
+            assert_eq!(Some(mod_rs), register(&without, day).as_deref());
+        }
+
+        let root = env::temp_dir().join(format!("aoc-new-{}", process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), mod_rs).unwrap();
+        let written = new_day(&root, next).unwrap();
+        assert_eq!(3, written.len());
+        assert_eq!("", fs::read_to_string(&written[1]).unwrap());
+        assert!(fs::read_to_string(&written[0])
+            .unwrap()
+            .contains(&format!("pub struct Day{next};")));
+        assert_eq!(registered, fs::read_to_string(&written[2]).unwrap());
+        assert!(new_day(&root, next).unwrap_err().contains("already exists"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+//! Shortest paths over graphs given as a function from a node to its neighbours, so nodes can be
+//! anything from grid positions to valve names.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// What a search from one or more sources found: how far every node it reached is from the
+/// closest source, and the way there.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, usize>,
+    /// The node each node was reached from, for every node but the sources.
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// The distance to every node that was reached.
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    /// The nodes of a shortest path from a source to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from all of `sources` at once, for graphs where every step costs 1.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+    };
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if search.distances.insert(source.clone(), 0).is_none() {
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node] + 1;
+        for next in neighbours(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm from all of `sources` at once, `neighbours` giving the cost of each step.
+pub fn dijkstra<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(sources, neighbours, |_| 0, |_| false).0
+}
+
+/// A* from `start` to the first node that is a `goal`, returning its distance and the path there.
+/// `heuristic` must never overestimate the distance left to a goal.
+pub fn astar<N, I>(
+    start: N,
+    goal: impl FnMut(&N) -> bool,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> usize,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let (search, goal) = best_first([start], neighbours, heuristic, goal);
+    let goal = goal?;
+    Some((search.distance(&goal)?, search.path(&goal)?))
+}
+
+/// A node waiting in the queue, ordered so the heap pops the lowest estimate first.
+struct Queued<N> {
+    estimate: usize,
+    distance: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Reverse(self.estimate).cmp(&Reverse(other.estimate))
+    }
+}
+
+/// Visits nodes in order of their distance plus `heuristic`, until one is a `goal`, which is
+/// returned with everything found so far.
+fn best_first<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Search<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::new();
+    for source in sources {
+        search.distances.insert(source.clone(), 0);
+        queue.push(Queued {
+            estimate: heuristic(&source),
+            distance: 0,
+            node: source,
+        });
+    }
+
+    while let Some(Queued { distance, node, .. }) = queue.pop() {
+        // a shorter way here was found after this one was queued
+        if distance > search.distances[&node] {
+            continue;
+        }
+        if goal(&node) {
+            return (search, Some(node));
+        }
+        for (next, cost) in neighbours(&node) {
+            let distance = distance + cost;
+            if search.distances.get(&next).is_none_or(|&d| distance < d) {
+                search.distances.insert(next.clone(), distance);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push(Queued {
+                    estimate: distance + heuristic(&next),
+                    distance,
+                    node: next,
+                });
+            }
+        }
+    }
+    (search, None)
+}
+
+/// The distances between every pair of `nodes`, by a breadth-first search from each.
+pub fn all_pairs_bfs<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, HashMap<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    nodes
+        .into_iter()
+        .map(|node| {
+            let search = bfs([node.clone()], &mut neighbours);
+            (node, search.distances)
+        })
+        .collect()
+}
+
+/// The distances between every pair of the nodes `0..n` joined by weighted, one-way `edges`, by
+/// the Floyd–Warshall algorithm. `None` where there is no way from one to the other.
+pub fn floyd_warshall(
+    n: usize,
+    edges: impl IntoIterator<Item = (usize, usize, usize)>,
+) -> Vec<Vec<Option<usize>>> {
+    let mut distances = vec![vec![None; n]; n];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[i] = Some(0);
+    }
+    for (from, to, cost) in edges {
+        let distance = &mut distances[from][to];
+        *distance = Some(distance.map_or(cost, |d: usize| d.min(cost)));
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if let (Some(a), Some(b)) = (distances[i][k], distances[k][j]) {
+                    if distances[i][j].is_none_or(|d| a + b < d) {
+                        distances[i][j] = Some(a + b);
+                    }
+                }
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_pairs_bfs, astar, bfs, dijkstra, floyd_warshall};
+    use crate::generate::Rng;
+
+    #[test]
+    fn test() {
+        // 0 - 1 - 2   4
+        //  \     /
+        //   - 3 -
+        let edges = [vec![1, 3], vec![0, 2], vec![1, 3], vec![0, 2], vec![]];
+        let search = bfs([0], |&n: &usize| edges[n].clone());
+        assert_eq!(Some(2), search.distance(&2));
+        assert_eq!(None, search.distance(&4));
+        assert_eq!(Some(vec![0, 1, 2]), search.path(&2));
+        assert_eq!(Some(vec![0]), search.path(&0));
+        assert_eq!(None, search.path(&4));
+
+        let search = bfs([0, 2], |&n: &usize| edges[n].clone());
+        assert!([0, 1, 0, 1]
+            .iter()
+            .enumerate()
+            .all(|(n, &d)| search.distance(&n) == Some(d)));
+
+        let all = all_pairs_bfs(0..5, |&n: &usize| edges[n].clone());
+        assert_eq!(Some(&2), all[&3].get(&1));
+        assert_eq!(None, all[&4].get(&0));
+
+        // shortest paths agree however they are found, on random weighted graphs
+        let mut rng = Rng::new(15);
+        for _ in 0..300 {
+            let n = rng.range(1..=12);
+            let edges: Vec<(usize, usize, usize)> = (0..rng.below(3 * n))
+                .map(|_| (rng.below(n), rng.below(n), rng.range(0..=9)))
+                .collect();
+            let neighbours = |&node: &usize| {
+                edges
+                    .iter()
+                    .filter(move |&&(from, _, _)| from == node)
+                    .map(|&(_, to, cost)| (to, cost))
+            };
+
+            let table = floyd_warshall(n, edges.iter().copied());
+            for (from, row) in table.iter().enumerate() {
+                let search = dijkstra([from], neighbours);
+                for (to, &distance) in row.iter().enumerate() {
+                    assert_eq!(distance, search.distance(&to));
+                    let found = astar(from, |&node| node == to, neighbours, |_| 0);
+                    assert_eq!(distance, found.as_ref().map(|&(d, _)| d));
+
+                    // the path is made of edges and costs what the search says
+                    if let Some(path) = search.path(&to) {
+                        let cost: usize = path
+                            .windows(2)
+                            .map(|step| neighbours(&step[0]).filter(|&(to, _)| to == step[1]))
+                            .map(|steps| steps.map(|(_, cost)| cost).min().unwrap())
+                            .sum();
+                        assert_eq!((from, Some(cost)), (path[0], distance));
+                    }
+                }
+            }
+        }
+    }
+}
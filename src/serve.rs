@@ -0,0 +1,455 @@
+//! `aoc serve`: the solvers behind a small HTTP/1.1 server, one thread per connection.
+//!
+//! - `POST /solve/<day>/<part>` solves the request body and answers with the answer, the parse
+//!   and solve times in nanoseconds and the parse error if there is one.
+//! - `GET /days` lists the days and whether each part answers with a number or text.
+//!
+//! Every reply is JSON and closes the connection.
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    days::{self, TEXT_PARTS},
+    Answer,
+};
+
+use crate::summary::{isolated, json_string};
+
+/// How much of the server a request can use.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// Puzzles solved at the same time; more requests are turned away until one is done.
+    pub jobs: usize,
+    /// The biggest puzzle input accepted, in bytes.
+    pub max_body: usize,
+    /// How long a request waits for its answer. A solve that takes longer is left to finish on
+    /// its own, still counted among the `jobs`.
+    pub timeout: Duration,
+}
+
+/// Connections handled at the same time, whether they are solving or not.
+const MAX_CONNECTIONS: usize = 64;
+/// The longest a request line and its headers can be.
+const MAX_HEAD: usize = 8 * 1024;
+/// How long a client may take to send its request or read the reply.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A fixed number of places, each given back when its [`Place`] is dropped.
+struct Places(AtomicUsize);
+
+struct Place(Arc<Places>);
+
+impl Places {
+    fn new(count: usize) -> Arc<Self> {
+        Arc::new(Places(AtomicUsize::new(count)))
+    }
+
+    fn take(self: &Arc<Self>) -> Option<Place> {
+        self.0
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |free| {
+                free.checked_sub(1)
+            })
+            .ok()
+            .map(|_| Place(Arc::clone(self)))
+    }
+}
+
+impl Drop for Place {
+    fn drop(&mut self) {
+        self.0 .0.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Reply {
+    status: u16,
+    body: String,
+}
+
+impl Reply {
+    fn error(status: u16, message: &str) -> Self {
+        Reply {
+            status,
+            body: format!(r#"{{"error":{}}}"#, json_string(message)),
+        }
+    }
+}
+
+/// Answers requests on `listener` until it fails.
+pub fn serve(listener: TcpListener, limits: Limits) -> io::Result<()> {
+    let limits = Arc::new(limits);
+    let solvers = Places::new(limits.jobs);
+    let connections = Places::new(MAX_CONNECTIONS);
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        let Some(connection) = connections.take() else {
+            let reply = Reply::error(503, "too many connections, try again later");
+            write_reply(&mut stream, &reply).ok();
+            continue;
+        };
+        let (limits, solvers) = (Arc::clone(&limits), Arc::clone(&solvers));
+        thread::spawn(move || {
+            let _connection = connection;
+            // a client that went away has nobody to tell
+            handle(stream, &limits, &solvers).ok();
+        });
+    }
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream, limits: &Limits, solvers: &Arc<Places>) -> io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let reply = match read_request(&mut reader, &mut stream, limits.max_body) {
+        Ok(request) => route(request, limits, solvers),
+        Err(reply) => reply,
+    };
+    write_reply(&mut stream, &reply)
+}
+
+/// Reads a request with a body of at most `max_body` bytes, telling `writer` to go on with the
+/// body if the client asks first. A request that cannot be read gets the reply saying why.
+fn read_request(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    max_body: usize,
+) -> Result<Request, Reply> {
+    let bad_request = |_| Reply::error(400, "could not read the request");
+    let mut head = reader.by_ref().take(MAX_HEAD as u64);
+    let mut line = String::new();
+    head.read_line(&mut line).map_err(bad_request)?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (words.next(), words.next(), words.next())
+    else {
+        return Err(Reply::error(400, "expected `<method> <path> HTTP/1.1`"));
+    };
+    let (method, path) = (
+        method.to_owned(),
+        target.split('?').next().unwrap().to_owned(),
+    );
+
+    let mut length = None;
+    let mut continue_first = false;
+    loop {
+        line.clear();
+        if head.read_line(&mut line).map_err(bad_request)? == 0 {
+            return Err(match head.limit() {
+                0 => Reply::error(431, "the request headers are too long"),
+                _ => Reply::error(400, "the request headers do not end"),
+            });
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            break;
+        };
+        let (name, value) = (name.trim(), value.trim());
+        if name.eq_ignore_ascii_case("content-length") {
+            length = Some(
+                value
+                    .parse::<usize>()
+                    .map_err(|_| Reply::error(400, "Content-Length must be a number"))?,
+            );
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Reply::error(411, "send the body with a Content-Length"));
+        } else if name.eq_ignore_ascii_case("expect") {
+            continue_first = value.eq_ignore_ascii_case("100-continue");
+        }
+    }
+
+    let length = length.unwrap_or(0);
+    if length > max_body {
+        return Err(Reply::error(
+            413,
+            &format!("the body is {length} bytes, the most accepted is {max_body}"),
+        ));
+    }
+    if continue_first && length > 0 {
+        writer
+            .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+            .map_err(bad_request)?;
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(bad_request)?;
+    Ok(Request { method, path, body })
+}
+
+fn route(request: Request, limits: &Limits, solvers: &Arc<Places>) -> Reply {
+    let segments: Vec<&str> = request.path.split('/').skip(1).collect();
+    match (request.method.as_str(), &segments[..]) {
+        ("GET", ["days"]) => Reply {
+            status: 200,
+            body: days_json(),
+        },
+        ("POST", ["solve", day, part]) => solve(day, part, request.body, limits, solvers),
+        (_, ["days"] | ["solve", _, _]) => Reply::error(405, "method not allowed"),
+        _ => Reply::error(404, "not found"),
+    }
+}
+
+/// Every day, and what each of its parts answers with.
+fn days_json() -> String {
+    let days: Vec<String> = days::DAYS
+        .iter()
+        .map(|day| {
+            let kind = |part| match TEXT_PARTS.contains(&(day.day, part)) {
+                true => "text",
+                false => "number",
+            };
+            format!(
+                r#"{{"day":{},"part1":"{}","part2":"{}"}}"#,
+                day.day,
+                kind(1),
+                kind(2)
+            )
+        })
+        .collect();
+    format!(r#"{{"days":[{}]}}"#, days.join(","))
+}
+
+fn solve(day: &str, part: &str, body: Vec<u8>, limits: &Limits, solvers: &Arc<Places>) -> Reply {
+    let Some(solution) = day.parse().ok().and_then(days::get) else {
+        return Reply::error(404, &format!("no solution for day `{day}`"));
+    };
+    let part: u8 = match part {
+        "1" => 1,
+        "2" => 2,
+        _ => return Reply::error(400, &format!("part must be 1 or 2, got `{part}`")),
+    };
+    let Ok(input) = String::from_utf8(body) else {
+        return Reply::error(400, "the input is not UTF-8");
+    };
+    let Some(place) = solvers.take() else {
+        return Reply::error(503, "every solver is busy, try again later");
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _place = place;
+        let solved = isolated(AssertUnwindSafe(|| {
+            let start = Instant::now();
+            let parsed = solution.parse(&input);
+            let parse_time = start.elapsed();
+            let start = Instant::now();
+            let answer = parsed.map(|parsed| solution.solve(&parsed, part));
+            (answer, parse_time, start.elapsed())
+        }));
+        // the request may have timed out and gone
+        sender.send(solved).ok();
+    });
+
+    let day = solution.day;
+    match receiver.recv_timeout(limits.timeout) {
+        Ok(Ok((Ok(answer), parse_time, solve_time))) => Reply {
+            status: 200,
+            body: format!(
+                r#"{{"day":{day},"part":{part},"answer":{},"parse_ns":{},"solve_ns":{},"error":null}}"#,
+                answer_json(&answer),
+                parse_time.as_nanos(),
+                solve_time.as_nanos()
+            ),
+        },
+        Ok(Ok((Err(err), parse_time, _))) => Reply {
+            status: 422,
+            body: format!(
+                r#"{{"day":{day},"part":{part},"answer":null,"parse_ns":{},"solve_ns":null,"error":{}}}"#,
+                parse_time.as_nanos(),
+                json_string(&err.to_string())
+            ),
+        },
+        Ok(Err(panic)) => Reply::error(500, &panic),
+        Err(_) => Reply::error(504, &format!("no answer after {:?}", limits.timeout)),
+    }
+}
+
+fn answer_json(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(text) => json_string(text),
+    }
+}
+
+fn write_reply(stream: &mut impl Write, reply: &Reply) -> io::Result<()> {
+    let reason = match reply.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Gateway Timeout",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        reply.status,
+        reply.body.len(),
+        reply.body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+        time::Duration,
+    };
+
+    use aoc::days;
+
+    use super::{read_request, route, serve, Limits, Places, Reply, Request};
+    use crate::http::Response;
+
+    fn post(path: &str, body: &str) -> Request {
+        Request {
+            method: "POST".to_owned(),
+            path: path.to_owned(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test() {
+        let limits = Limits {
+            jobs: 2,
+            max_body: 100,
+            timeout: Duration::from_secs(60),
+        };
+        let solvers = Places::new(limits.jobs);
+        let rounds = "A Y\nB X\nC Z\n";
+
+        let request = format!(
+            "POST /solve/2/1?x=y HTTP/1.1\r\nHost: aoc\r\ncontent-length: {}\r\n\
+             Expect: 100-continue\r\n\r\n{rounds}",
+            rounds.len()
+        );
+        let mut written = Vec::new();
+        let parsed = read_request(&mut request.as_bytes(), &mut written, 100)
+            .ok()
+            .unwrap();
+        assert_eq!(("POST", "/solve/2/1"), (&*parsed.method, &*parsed.path));
+        assert_eq!(rounds.as_bytes(), parsed.body);
+        assert_eq!(b"HTTP/1.1 100 Continue\r\n\r\n", &written[..]);
+        let status = |request: &str| {
+            read_request(&mut request.as_bytes(), &mut Vec::new(), 100)
+                .err()
+                .map(|reply| reply.status)
+        };
+        assert_eq!(
+            Some(413),
+            status("POST / HTTP/1.1\r\nContent-Length: 101\r\n\r\n")
+        );
+        assert_eq!(
+            Some(411),
+            status("POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n")
+        );
+        assert_eq!(
+            Some(431),
+            status(&format!("GET / HTTP/1.1\r\nA: {}", "a".repeat(9000)))
+        );
+        assert_eq!(Some(400), status("GET /\r\n\r\n"));
+
+        let reply = route(parsed, &limits, &solvers);
+        assert_eq!(200, reply.status);
+        assert!(reply
+            .body
+            .starts_with(r#"{"day":2,"part":1,"answer":15,"parse_ns":"#));
+        assert!(reply.body.ends_with(r#","error":null}"#));
+        let reply = route(
+            post("/solve/5/1", days::get(5).unwrap().input),
+            &limits,
+            &solvers,
+        );
+        assert!(reply.body.contains(r#""answer":"LBLVVTVLP""#));
+        let reply = route(post("/solve/2/1", "Q Y\n"), &limits, &solvers);
+        assert_eq!(422, reply.status);
+        assert!(reply.body.contains(r#""answer":null,"#));
+        assert!(reply.body.ends_with(
+            r#""error":"day 2, line 1, column 1: expected one of `A`, `B`, `C`, found `Q`"}"#
+        ));
+        assert_eq!(
+            404,
+            route(post("/solve/25/1", ""), &limits, &solvers).status
+        );
+        assert_eq!(400, route(post("/solve/2/3", ""), &limits, &solvers).status);
+        assert_eq!(405, route(post("/days", ""), &limits, &solvers).status);
+        assert_eq!(404, route(post("/", ""), &limits, &solvers).status);
+
+        let days = Request {
+            method: "GET".to_owned(),
+            ..post("/days", "")
+        };
+        let reply = route(days, &limits, &solvers);
+        assert!(reply
+            .body
+            .starts_with(r#"{"days":[{"day":1,"part1":"number","part2":"number"},"#));
+        assert!(reply
+            .body
+            .contains(r#"{"day":5,"part1":"text","part2":"text"}"#));
+        assert!(reply
+            .body
+            .contains(r#"{"day":10,"part1":"number","part2":"text"}"#));
+
+        // a solve that runs out of time keeps its solver until it is done
+        let limits = Limits {
+            jobs: 1,
+            timeout: Duration::from_millis(1),
+            ..limits
+        };
+        let solvers = Places::new(limits.jobs);
+        let day16 = days::get(16).unwrap().input;
+        assert_eq!(
+            Reply::error(504, "no answer after 1ms"),
+            route(post("/solve/16/2", day16), &limits, &solvers)
+        );
+        assert_eq!(
+            503,
+            route(post("/solve/2/1", rounds), &limits, &solvers).status
+        );
+
+        // and over the network
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let limits = Limits {
+            timeout: Duration::from_secs(60),
+            ..limits
+        };
+        thread::spawn(move || serve(listener, limits));
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /solve/2/2 HTTP/1.1\r\nContent-Length: {}\r\n\r\n{rounds}",
+            rounds.len()
+        )
+        .unwrap();
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).unwrap();
+        let response = Response::parse(&raw).unwrap();
+        assert_eq!(200, response.status);
+        assert_eq!(Some("application/json"), response.header("content-type"));
+        assert!(response.body.contains(r#""answer":12,"#));
+    }
+}
@@ -0,0 +1,73 @@
+//! The days that can solve an input as they read it, for inputs too big to load into memory.
+//! Each reads its input once and works out both parts along the way, keeping no more than a line
+//! and what the puzzle itself needs to remember.
+
+use std::io::BufRead;
+
+use crate::{
+    days::{day1, day10, day2, day3, day4, day6, day9},
+    parse::ReadError,
+    Answer,
+};
+
+/// The days that can stream their input.
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 6, 9, 10];
+
+/// The answers to both parts of `day`, a day in [`DAYS`], reading `input` as it goes.
+pub fn solve(day: u8, input: impl BufRead) -> Result<[Answer; 2], ReadError> {
+    fn answers(parts: (impl Into<Answer>, impl Into<Answer>)) -> [Answer; 2] {
+        [parts.0.into(), parts.1.into()]
+    }
+
+    Ok(match day {
+        1 => answers(day1::stream(input)?),
+        2 => answers(day2::stream(input)?),
+        3 => answers(day3::stream(input)?),
+        4 => answers(day4::stream(input)?),
+        6 => answers(day6::stream(input)?),
+        9 => answers(day9::stream(input)?),
+        10 => answers(day10::stream(input)?),
+        _ => unreachable!("day {day} cannot stream its input"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::{solve, DAYS};
+    use crate::{days, generate::Rng};
+
+    #[test]
+    fn test() {
+        let mut rng = Rng::new(21);
+        for &day in DAYS {
+            let solution = days::get(day).unwrap();
+            let mut inputs = vec![solution.input.to_owned()];
+            inputs.extend((0..5).map(|seed| solution.generate(seed, 50)));
+
+            // the same answers and errors as solving the whole input, however it is read
+            for input in inputs {
+                let mut bytes = input.clone().into_bytes();
+                for _ in 0..20 {
+                    let corrupted = String::from_utf8_lossy(&bytes).into_owned();
+                    let expected = solution
+                        .parse(&corrupted)
+                        .map(|parsed| [1, 2].map(|part| solution.solve(&parsed, part)))
+                        .map_err(|err| err.to_string());
+                    let reader = BufReader::with_capacity(3, corrupted.as_bytes());
+                    let streamed = solve(day, reader).map_err(|err| err.to_string());
+                    assert_eq!(expected, streamed, "day {day}: {corrupted:?}");
+
+                    let at = rng.below(bytes.len());
+                    bytes[at] = *rng.choose(b"\n\r -,09AaZz\xc3\xa9");
+                    let corrupted = String::from_utf8_lossy(&bytes).into_owned();
+                    if solution.parse(&corrupted).is_ok() {
+                        // a valid input may not have an answer, see only what fails to parse
+                        bytes = input.clone().into_bytes();
+                    }
+                }
+            }
+        }
+    }
+}
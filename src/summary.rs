@@ -0,0 +1,265 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{days::Day, Answer};
+
+/// How one day went.
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    /// The answer of each part that was solved.
+    pub answers: Vec<(u8, Answer)>,
+    /// Reading, parsing and solving.
+    pub time: Duration,
+    /// Why a day stopped short: its input could not be read or parsed, or a part panicked.
+    pub error: Option<String>,
+}
+
+/// Everything `run --all` did.
+#[derive(Debug)]
+pub struct Summary {
+    pub jobs: usize,
+    /// Ordered by day.
+    pub outcomes: Vec<Outcome>,
+    pub wall_time: Duration,
+}
+
+impl Summary {
+    pub fn failed(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.error.is_some()).count()
+    }
+}
+
+thread_local! {
+    /// Where and why the last panic on this thread happened, as the panic hook saw it.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Solves `parts` of every day on `jobs` threads, each thread taking the next day once it is done
+/// with one. Panics are caught and reported as the failure of their day.
+pub fn run<'a>(
+    days: &[&Day],
+    parts: &[u8],
+    jobs: usize,
+    input: impl Fn(&Day) -> Result<Cow<'a, str>, String> + Sync,
+) -> Summary {
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::with_capacity(days.len()));
+
+    quietly(|| {
+        thread::scope(|scope| {
+            for _ in 0..jobs.clamp(1, days.len().max(1)) {
+                scope.spawn(|| {
+                    while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let outcome = solve(day, parts, &input);
+                        outcomes.lock().unwrap().push(outcome);
+                    }
+                });
+            }
+        })
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|outcome| outcome.day);
+    Summary {
+        jobs,
+        outcomes,
+        wall_time: start.elapsed(),
+    }
+}
+
+fn solve<'a>(
+    day: &Day,
+    parts: &[u8],
+    input: &impl Fn(&Day) -> Result<Cow<'a, str>, String>,
+) -> Outcome {
+    let start = Instant::now();
+    let mut outcome = Outcome {
+        day: day.day,
+        answers: Vec::new(),
+        time: Duration::ZERO,
+        error: None,
+    };
+
+    let parsed = isolated(AssertUnwindSafe(|| input(day)))
+        .and_then(|input| input)
+        .and_then(|input| {
+            isolated(AssertUnwindSafe(|| day.parse(&input)))?.map_err(|err| err.to_string())
+        });
+    match parsed {
+        Ok(parsed) => {
+            for &part in parts {
+                match isolated(AssertUnwindSafe(|| day.solve(&parsed, part))) {
+                    Ok(answer) => outcome.answers.push((part, answer)),
+                    Err(err) => {
+                        outcome.error = Some(format!("part {part}: {err}"));
+                        break;
+                    }
+                }
+            }
+        }
+        Err(err) => outcome.error = Some(err),
+    }
+
+    outcome.time = start.elapsed();
+    outcome
+}
+
+/// Runs `f` with panics recorded for [`isolated`] to report rather than printed as they happen.
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let message = info.to_string().replace('\n', ": ");
+        PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+    }));
+    let result = f();
+    panic::set_hook(hook);
+    result
+}
+
+/// Runs `f`, turning a panic into an error saying where and why it happened.
+pub fn isolated<T>(f: impl FnOnce() -> T + panic::UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(f).map_err(|payload| {
+        PANIC
+            .with(|panic| panic.borrow_mut().take())
+            .unwrap_or_else(|| format!("panicked: {}", message(&*payload)))
+    })
+}
+
+/// The message a panic was started with.
+fn message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown cause", String::as_str),
+    }
+}
+
+/// The time and result of every day, then the totals.
+pub fn table(summary: &Summary) -> String {
+    let mut table = format!("{:>3}  {:>10}  {}\n", "day", "time", "result");
+    for outcome in &summary.outcomes {
+        let result = outcome
+            .error
+            .as_ref()
+            .map_or("ok".to_owned(), |err| format!("failed, {err}"));
+        table += &format!("{:>3}  {:>10.1?}  {result}\n", outcome.day, outcome.time);
+    }
+    let days = summary.outcomes.len();
+    let failed = summary.failed();
+    let threads = match summary.jobs {
+        1 => "1 thread".to_owned(),
+        n => format!("{n} threads"),
+    };
+    table += &format!(
+        "{days} days in {:.1?} on {threads}: {} ok, {failed} failed\n",
+        summary.wall_time,
+        days - failed
+    );
+    table
+}
+
+/// The summary as a JSON document, durations in nanoseconds.
+pub fn json(summary: &Summary) -> String {
+    let days: Vec<String> = summary
+        .outcomes
+        .iter()
+        .map(|outcome| {
+            let answers: Vec<String> = outcome
+                .answers
+                .iter()
+                .map(|(part, answer)| match answer {
+                    Answer::Number(n) => format!(r#""part{part}":{n}"#),
+                    Answer::Text(text) => format!(r#""part{part}":{}"#, json_string(text)),
+                })
+                .collect();
+            let error = outcome
+                .error
+                .as_deref()
+                .map_or("null".to_owned(), json_string);
+            format!(
+                r#"{{"day":{},"time_ns":{},"answers":{{{}}},"error":{error}}}"#,
+                outcome.day,
+                outcome.time.as_nanos(),
+                answers.join(",")
+            )
+        })
+        .collect();
+    format!(
+        r#"{{"jobs":{},"wall_time_ns":{},"days":[{}]}}"#,
+        summary.jobs,
+        summary.wall_time.as_nanos(),
+        days.join(",")
+    )
+}
+
+/// `s` as a JSON string, quotes included.
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use aoc::{days, Answer};
+
+    use super::{isolated, json, json_string, quietly, run, table};
+
+    #[test]
+    fn test() {
+        let days = [6, 5, 1].map(|day| days::get(day).unwrap());
+        let summary = run(&days, &[1, 2], 2, |day| match day.day {
+            1 => Err("could not read input for day 1".to_owned()),
+            6 => Ok(Cow::Borrowed("mjqjpqmgbljsphdztnvjfqwrcgsmlb")),
+            _ => Ok(Cow::Borrowed(day.input)),
+        });
+        assert_eq!(
+            vec![1, 5, 6],
+            summary.outcomes.iter().map(|o| o.day).collect::<Vec<_>>()
+        );
+        assert_eq!(1, summary.failed());
+        let day6 = &summary.outcomes[2];
+        assert_eq!(
+            vec![(1, Answer::Number(7)), (2, Answer::Number(19))],
+            day6.answers
+        );
+        assert!(table(&summary).contains("  1  "));
+        assert!(table(&summary).ends_with("on 2 threads: 2 ok, 1 failed\n"));
+        assert!(json(&summary)
+            .contains(r#""answers":{"part1":"LBLVVTVLP","part2":"TPFFBDRJD"},"error":null}"#));
+
+        // a panic is the failure of its day, saying where it happened
+        let err = quietly(|| isolated(|| -> u8 { panic!("no {}", "beacon") })).unwrap_err();
+        assert!(
+            err.contains("src/summary.rs") && err.ends_with("no beacon"),
+            "{err}"
+        );
+        assert_eq!(Ok(3), isolated(|| 3));
+
+        assert_eq!(r#""a\"b\\c\nd\u0007""#, json_string("a\"b\\c\nd\u{7}"));
+    }
+}
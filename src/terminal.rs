@@ -0,0 +1,199 @@
+use std::{
+    env,
+    io::{self, BufReader, IsTerminal, Read, Write},
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::animate::{Simulation, Viewport};
+
+use crate::cli::Animate;
+
+/// Keys read while an animation is running.
+const HELP: &str = "space: pause/resume  n: single step  q: quit";
+
+/// The terminal, with keys read one at a time and the cursor hidden, until dropped.
+pub struct Terminal {
+    /// The `stty` settings to restore, if the terminal's were changed.
+    saved: Option<String>,
+    /// Keys pressed, if stdin is a terminal.
+    keys: Option<Receiver<u8>>,
+    width: usize,
+    height: usize,
+}
+
+impl Terminal {
+    pub fn open() -> io::Result<Self> {
+        let interactive = io::stdin().is_terminal();
+        // without echo or line buffering, and with Ctrl-C read as a key so the settings are
+        // always restored
+        let saved = if interactive {
+            stty(&["-g"]).filter(|_| stty(&["-icanon", "-echo", "-isig", "min", "1"]).is_some())
+        } else {
+            None
+        };
+        let keys = interactive.then(|| {
+            let (sender, keys) = mpsc::channel();
+            thread::spawn(move || {
+                for key in BufReader::new(io::stdin()).bytes().map_while(Result::ok) {
+                    if sender.send(key).is_err() {
+                        break;
+                    }
+                }
+            });
+            keys
+        });
+
+        let (width, height) = size();
+        print!("\x1b[?25l");
+        io::stdout().flush()?;
+        Ok(Terminal {
+            saved,
+            keys,
+            width,
+            height,
+        })
+    }
+
+    /// Plays `simulation` to its end, or until `q` is pressed, returning whether it finished.
+    pub fn animate(
+        &mut self,
+        simulation: &mut dyn Simulation,
+        options: &Animate,
+    ) -> io::Result<bool> {
+        // the status and help lines take two rows, and one is left for the cursor
+        let mut view = Viewport::centered(
+            self.width,
+            self.height.saturating_sub(3),
+            simulation.focus(),
+        );
+        let frame = Duration::from_secs(1) / options.fps;
+        let mut paused = false;
+        let mut finished = false;
+        let mut next_frame = Instant::now();
+        print!("\x1b[2J");
+
+        loop {
+            let mut single_step = false;
+            while let Some(key) = self.key(next_frame) {
+                match key {
+                    b' ' => paused = !paused,
+                    b'n' => {
+                        paused = true;
+                        single_step = true;
+                        break;
+                    }
+                    // q or Ctrl-C
+                    b'q' | 0x03 => return Ok(false),
+                    _ => {}
+                }
+            }
+
+            if !paused || single_step {
+                let steps = if single_step { 1 } else { options.steps };
+                for _ in 0..steps {
+                    if !simulation.step() {
+                        finished = true;
+                        break;
+                    }
+                }
+            }
+            view.follow(simulation.focus());
+            self.draw(&view, &*simulation, paused)?;
+            if finished {
+                return Ok(true);
+            }
+
+            next_frame += frame;
+            let now = Instant::now();
+            if next_frame < now {
+                // too slow to keep up, so drop frames rather than rushing to catch up
+                next_frame = now;
+            }
+        }
+    }
+
+    /// The next key pressed before `deadline`, or `None` once it has passed.
+    fn key(&self, deadline: Instant) -> Option<u8> {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        match &self.keys {
+            Some(keys) => match keys.recv_timeout(timeout) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                    None
+                }
+            },
+            None => {
+                thread::sleep(timeout);
+                None
+            }
+        }
+    }
+
+    fn draw(&self, view: &Viewport, simulation: &dyn Simulation, paused: bool) -> io::Result<()> {
+        let mut frame = String::from("\x1b[H");
+        let status = simulation.status();
+        let state = if paused { "  [paused]" } else { "" };
+        frame += &format!("{status}{state}\x1b[K\n");
+        for line in view.render(simulation) {
+            frame += &line;
+            frame += "\x1b[K\n";
+        }
+        frame += HELP;
+        frame += "\x1b[K\n";
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(frame.as_bytes())?;
+        stdout.flush()
+    }
+
+    /// Waits for a key, if there is anyone to press one.
+    pub fn wait_for_key(&self) {
+        if let Some(keys) = &self.keys {
+            keys.recv().ok();
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h");
+        io::stdout().flush().ok();
+        if let Some(saved) = &self.saved {
+            stty(&[saved.as_str()]);
+        }
+    }
+}
+
+/// Runs `stty` on the terminal, returning what it printed.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// The terminal's width and height, from `stty size`, else from `COLUMNS` and `LINES`, else
+/// 80 by 24.
+fn size() -> (usize, usize) {
+    let stty = stty(&["size"]).and_then(|size| {
+        let (rows, columns) = size.split_once(' ')?;
+        Some((columns.parse().ok()?, rows.parse().ok()?))
+    });
+    stty.unwrap_or_else(|| {
+        let var = |name, default| {
+            env::var(name)
+                .ok()
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(default)
+        };
+        (var("COLUMNS", 80), var("LINES", 24))
+    })
+}
@@ -0,0 +1,174 @@
+//! Intermediate results the days report along the way, like the directory sizes of day 7, written
+//! as lines of `key=value` pairs:
+//!
+//! ```text
+//! day=7 event=directory path=/a/e size=584
+//! ```
+//!
+//! Nothing is traced until a sink is set. Until then a [`trace!`] only checks a flag, without
+//! evaluating its values.
+
+use std::{
+    cell::RefCell,
+    fmt::{Display, Write as _},
+    io::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+/// How many sinks are set: the global one and the captures in progress.
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+thread_local! {
+    /// Where [`capture`] collects this thread's lines, taking precedence over the global sink.
+    static CAPTURE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Reports `key = value` pairs for `event` of `day` if tracing is on.
+macro_rules! trace {
+    ($day:expr, $event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit(
+                $day,
+                $event,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Display)),*],
+            );
+        }
+    };
+}
+pub(crate) use trace;
+
+/// Whether anything is listening.
+#[inline]
+pub fn enabled() -> bool {
+    ACTIVE.load(Ordering::Relaxed) > 0
+}
+
+/// Sends every thread's lines to `sink`, or stops tracing with `None`, returning the previous
+/// sink so it can be flushed.
+pub fn set_sink(sink: Option<Box<dyn Write + Send>>) -> Option<Box<dyn Write + Send>> {
+    let mut current = SINK.lock().unwrap();
+    match (current.is_some(), sink.is_some()) {
+        (false, true) => ACTIVE.fetch_add(1, Ordering::Relaxed),
+        (true, false) => ACTIVE.fetch_sub(1, Ordering::Relaxed),
+        _ => 0,
+    };
+    std::mem::replace(&mut current, sink)
+}
+
+/// Runs `f`, returning the lines it traced on this thread.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let outer = CAPTURE.with(|capture| capture.borrow_mut().replace(String::new()));
+    ACTIVE.fetch_add(1, Ordering::Relaxed);
+    let result = f();
+    ACTIVE.fetch_sub(1, Ordering::Relaxed);
+    let lines = CAPTURE.with(|capture| std::mem::replace(&mut *capture.borrow_mut(), outer));
+    (result, lines.unwrap_or_default())
+}
+
+/// Writes a line for [`trace!`].
+pub fn emit(day: u8, event: &str, fields: &[(&str, &dyn Display)]) {
+    let mut line = format!("day={day} event={}", quoted(event));
+    for (key, value) in fields {
+        write!(line, " {key}={}", quoted(&value.to_string())).unwrap();
+    }
+    line.push('\n');
+
+    let captured = CAPTURE.with(|capture| match &mut *capture.borrow_mut() {
+        Some(lines) => {
+            lines.push_str(&line);
+            true
+        }
+        None => false,
+    });
+    if !captured {
+        if let Some(sink) = &mut *SINK.lock().unwrap() {
+            // tracing is best effort, it never stops a day
+            sink.write_all(line.as_bytes()).ok();
+        }
+    }
+}
+
+/// `value` as it is, or in double quotes if it is empty or has spaces, quotes or `=` in it.
+fn quoted(value: &str) -> String {
+    if !value.is_empty() && !value.contains(|c: char| c.is_whitespace() || c == '"' || c == '=') {
+        return value.to_owned();
+    }
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use super::{capture, enabled, set_sink};
+
+    /// A sink that can still be read once it has been handed over.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test() {
+        let mut evaluated = false;
+        let mut value = || {
+            evaluated = true;
+            1
+        };
+        let ((), lines) = capture(|| {
+            trace!(7, "directory", path = "/a/e", size = 584);
+            trace!(16, "open", valve = "a b", note = "", quote = "say \"hi\"");
+        });
+        assert_eq!(
+            concat!(
+                "day=7 event=directory path=/a/e size=584\n",
+                "day=16 event=open valve=\"a b\" note=\"\" quote=\"say \\\"hi\\\"\"\n"
+            ),
+            lines
+        );
+
+        // the global sink gets what is not captured
+        let sink = Shared::default();
+        assert!(set_sink(Some(Box::new(sink.clone()))).is_none());
+        assert!(enabled());
+        trace!(13, "pair", index = 1, in_order = true);
+        let ((), lines) = capture(|| trace!(13, "pair", index = 2, in_order = false));
+        assert!(set_sink(None).is_some());
+        assert_eq!("day=13 event=pair index=2 in_order=false\n", lines);
+        let global = String::from_utf8(sink.0.lock().unwrap().clone()).unwrap();
+        assert!(global.contains("day=13 event=pair index=1 in_order=true\n"));
+        assert!(!global.contains("index=2"));
+
+        // other tests may be tracing, but this thread is not, so nothing is evaluated
+        if !enabled() {
+            trace!(1, "never", value = value());
+            assert!(!evaluated);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Builds `tests/ffi/solve.c` against the shared library with the system C compiler (`$CC`, or
+//! `cc`) and runs it.
+
+use std::{env, path::Path, process::Command};
+
+#[test]
+fn test() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // this test runs from target/<profile>/deps, next to the library cargo built for it
+    let test = env::current_exe().unwrap();
+    let lib_dir = test.parent().unwrap();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("solve");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let compiled = Command::new(&cc)
+        .arg(root.join("tests/ffi/solve.c"))
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-laoc", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap_or_else(|err| panic!("could not run `{cc}`: {err}"));
+    assert!(compiled.success(), "`{cc}` failed");
+
+    let output = Command::new(&program).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!("ok\n", String::from_utf8_lossy(&output.stdout));
+}
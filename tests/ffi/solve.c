@@ -0,0 +1,54 @@
+/* Solves through the C interface of the shared library, as a tool written in C would. */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void check(int ok, const char *what) {
+    if (!ok) {
+        fprintf(stderr, "failed: %s\n", what);
+        failures++;
+    }
+}
+
+int main(void) {
+    const char *rounds = "A Y\nB X\nC Z\n";
+    char out[64];
+    size_t len = 0;
+
+    aoc_status status = aoc_solve(2, 1, rounds, strlen(rounds), out, sizeof out, &len);
+    check(status == AOC_OK && strcmp(out, "15") == 0 && len == 2, "day 2 part 1");
+    status = aoc_solve(2, 2, rounds, strlen(rounds), out, sizeof out, NULL);
+    check(status == AOC_OK && strcmp(out, "12") == 0, "day 2 part 2");
+
+    check(aoc_solve(25, 1, rounds, strlen(rounds), out, sizeof out, NULL) == AOC_UNKNOWN_DAY,
+          "unknown day");
+    check(aoc_solve(2, 3, rounds, strlen(rounds), out, sizeof out, NULL) == AOC_UNKNOWN_PART,
+          "unknown part");
+
+    status = aoc_solve(2, 1, "Q Y\n", 4, out, sizeof out, NULL);
+    check(status == AOC_PARSE_ERROR && strstr(out, "line 1, column 1") != NULL, "parse error");
+
+    /* ask for the size, then solve again with a buffer big enough */
+    const char *crates = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n"
+                         "move 1 from 2 to 1\nmove 3 from 1 to 3\n"
+                         "move 2 from 2 to 1\nmove 1 from 1 to 2\n";
+    status = aoc_solve(5, 1, crates, strlen(crates), NULL, 0, &len);
+    check(status == AOC_BUFFER_TOO_SMALL && len == 3, "buffer too small");
+    char *answer = malloc(len + 1);
+    status = aoc_solve(5, 1, crates, strlen(crates), answer, len + 1, NULL);
+    check(status == AOC_OK && strcmp(answer, "CMZ") == 0, "day 5 part 1");
+    free(answer);
+
+    check(aoc_solve(2, 1, NULL, 3, out, sizeof out, NULL) == AOC_INVALID_ARGUMENT,
+          "null input");
+
+    if (failures > 0) {
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}